@@ -1,7 +1,14 @@
+extern crate either;
 extern crate num_traits;
+extern crate unicode_general_category;
+extern crate unicode_normalization;
+extern crate unicode_xid;
 mod parser;
 
-pub use parser::{ParserFn, Position, ParserState, ParserSuccess, ParserFailure, ParserResult, Parser};
+pub use parser::{ParserFn, Position, Span, ParserState, ParserSuccess, ParserFailure, ParserResult, FailureSeverity, Parser};
+pub use parser::{ParserTrace, TraceEntry, TraceOutcome, ParserProfile, ProfileEntry, ParserLimits};
 pub use parser::char_parsers::*;
-pub use parser::combinators::{choice, choice_l, attempt};
-pub use parser::combinators::{many::*, sep_by::*, many_till::*, pipe::*};
+pub use unicode_general_category::GeneralCategory;
+pub use either::Either;
+pub use parser::combinators::{choice, choice_l, attempt, cut, lookahead, followed_by, followed_by_l, not_followed_by, not_followed_by_l};
+pub use parser::combinators::{many::*, sep_by::*, many_till::*, pipe::*, operator_precedence::*, chain::*, permutation::*, alt::*, rule::*};
@@ -1,6 +1,13 @@
 use super::{ParserState, ParserSuccess, ParserFailure, Parser};
+use super::trie::Trie;
+
+use std::collections::HashSet;
+use std::rc::Rc;
 
 use num_traits::{Float, PrimInt};
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_normalization::UnicodeNormalization;
+use unicode_xid::UnicodeXID;
 
 /// `p_char` takes a single character as the `target` and returns a parser. When the parser is applied to the input string, it will 
 /// return the character parsed as a `ParserSuccess` if the next character in the input string matches the `target`. 
@@ -82,7 +89,7 @@ where T: 'static
             move |state: &mut ParserState| {
                 match state.get_remaining_input().chars().next() {
                     Some(c) if c == target => {
-                        state.move_state_forward(target.len_utf8());
+                        state.move_state_forward(target.len_utf8())?;
                         Ok(ParserSuccess::new(return_value, state.get_position()))
                     },
                     Some(c) => {
@@ -134,7 +141,7 @@ pub fn satisfy(f: Box<dyn Fn (char) -> bool>) -> Parser<char> {
             move |state: &mut ParserState| {
                 match state.get_remaining_input().chars().next() {
                     Some(c) if f(c) => {
-                        state.move_state_forward(c.len_utf8());
+                        state.move_state_forward(c.len_utf8())?;
                         Ok(ParserSuccess::new(c, state.get_position()))
                     },
                     _ => {
@@ -187,8 +194,8 @@ pub fn many_satisfy(f: Box<dyn Fn (char) -> bool>) -> Parser<String> {
                         break;
                     }
                 }
-                let result = state.get_slice(count).unwrap_or(String::new());
-                state.move_state_forward(count);
+                let result = state.get_slice(count).unwrap_or_default();
+                state.move_state_forward(count)?;
                 Ok(ParserSuccess::new(result, state.get_position()))
             }
         );
@@ -196,282 +203,1456 @@ pub fn many_satisfy(f: Box<dyn Fn (char) -> bool>) -> Parser<String> {
     Parser::new(parser_fn)
 }
 
-/// `p_string` takes a String as an argument and returns a parser success with the expected String value if the next string slice of the input string is a match, otherwise it returns a parser failure.
+/// `many_satisfy_range` takes a minimum count (`min`), a maximum count (`max`), and a function (`f`) of type `(char) -> bool` and returns a parser.
+/// When the parser is applied to the input string, it will apply the function `f` to each character in sequence until the function `f` returns
+/// false or `max` characters have been parsed. All successfully parsed characters are collected into a single string and returned as the value of
+/// a `ParserSuccess`.
+/// 
+/// # Errors
+/// `many_satisfy_range` will return a `ParserFailure` with a severity of `Error` if fewer than `min` characters return true when applied to the
+/// function `f`. The failure states how many characters were expected and how many were found, and the parser state is unchanged.
 /// 
 /// # Examples
 /// 
 /// ```
-/// use rusty_parsec::*;
-/// 
-/// let expected = Ok(ParserSuccess::new("hello".to_string(), Position::new(1, 6, 5)));
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("ff00"), 
+///     Position::new(1, 5, 4)
+/// ));
 /// 
-/// let actual = 
-///     p_string("hello".to_string())
-///         .run("hello, world".to_string());
+/// let actual = many_satisfy_range(1, 4, Box::new(|c: char| c.is_ascii_hexdigit()))
+///     .run(String::from("ff00cc"));
 /// 
 /// assert_eq!(actual, expected);
 /// ```
-pub fn p_string(target: String) -> Parser<String> {
-    string_return(target.clone(), target)
+pub fn many_satisfy_range(min: usize, max: usize, f: Box<dyn Fn (char) -> bool>) -> Parser<String> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let mut count = 0;
+                let mut length = 0;
+                for c in state.get_remaining_input().chars().take(max) {
+                    if f(c) {
+                        count += 1;
+                        length += c.len_utf8();
+                    } else {
+                        break;
+                    }
+                }
+
+                if count < min {
+                    let expected = if min == max {
+                        format!("{} chars satisfying the condition", min)
+                    } else {
+                        format!("at least {} chars satisfying the condition", min)
+                    };
+
+                    return Err(ParserFailure::new_err(
+                        expected,
+                        Some(format!("{} chars", count)),
+                        state.get_position()
+                    ))
+                }
+
+                let result = state.get_slice(length).unwrap_or_default();
+                state.move_state_forward(length)?;
+                Ok(ParserSuccess::new(result, state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
 }
 
-/// `skip_string` takes a String as an argument and returns a parser success of `()` if the next string slice of the input string is a match, otherwise it returns a parser failure.
+/// `any_of` takes a string of characters (`chars`) and returns a parser. When the parser is applied to the input string, it will 
+/// return the character parsed as a `ParserSuccess` if the next character in the input string is one of the characters in `chars`.
+/// 
+/// # Errors
+/// `any_of` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not one of the characters in `chars`.
 /// 
 /// # Examples
 /// 
 /// ```
-/// use rusty_parsec::*;
-/// 
-/// let expected = Ok(ParserSuccess::new((), Position::new(1, 6, 5)));
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     '*', 
+///     Position::new(1, 2, 1)
+/// ));
 /// 
-/// let actual =  
-///     skip_string("hello".to_string())
-///         .run("hello, world".to_string());
+/// let actual = any_of("+-*/")
+///     .run(String::from("*2"));
 /// 
 /// assert_eq!(actual, expected);
 /// ```
-pub fn skip_string(target: String) -> Parser<()> {
-    string_return(target, ())
+pub fn any_of(chars: &str) -> Parser<char> {
+    let (f, label) = any_of_class(chars);
+    char_class(f, label)
 }
 
-/// `string_return` takes a String as an argument and returns a parser success of the value supplied as the second argument of the function if the next string slice of the input string is a match, otherwise it returns a parser failure.
+/// `many_any_of` works like `any_of`, but applies the parser repeatedly until the next character in the input string is not one of the characters in `chars`.
+/// All successfully parsed characters are returned as a single string.
+/// 
+/// # Errors
+/// `many_any_of` will never return an error. If the first character is not one of the characters in `chars`, it returns an empty string.
 /// 
 /// # Examples
 /// 
 /// ```
-/// use rusty_parsec::*;
-/// 
-/// let expected = Ok(ParserSuccess::new(true, Position::new(1, 6, 5)));
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("+-+"), 
+///     Position::new(1, 4, 3)
+/// ));
 /// 
-/// let actual = 
-///     string_return("hello".to_string(), true)
-///         .run("hello, world".to_string());
+/// let actual = many_any_of("+-")
+///     .run(String::from("+-+2"));
 /// 
 /// assert_eq!(actual, expected);
 /// ```
-pub fn string_return<T>(target: String, return_value: T) -> Parser<T> 
-where T: 'static
-{
-    let parser_fn =
-        Box::new(
-            move |state: &mut ParserState| {
-                match state.get_slice(target.len()) {
-                    Some(s) if s == target => {
-                        state.move_state_forward(target.len());
-                        Ok(ParserSuccess::new(return_value, state.get_position()))
-                    },
-                    Some(s) => {
-                        Err(ParserFailure::new_err(
-                            target,
-                            Some(s),
-                            state.get_position()
-                        ))
-                    },
-                    None => {
-                        Err(ParserFailure::new_err(
-                            target,
-                            None,
-                            state.get_position()
-                        ))
-                    },
-                }
-            }
-        );
-
-    Parser::new(parser_fn)
+pub fn many_any_of(chars: &str) -> Parser<String> {
+    let (f, label) = any_of_class(chars);
+    many_char_class(f, label, false)
 }
 
-/// `p_i32` tries to parse the input string as an integer and if it succeeds, returns the result as an i32 integer.
+/// `many_1_any_of` works like `many_any_of`, except that at least one character must be parsed.
+/// 
+/// # Errors
+/// `many_1_any_of` will return a `ParserFailure` with a severity of `Error` if the first character is not one of the characters in `chars`.
 /// 
 /// # Examples
 /// 
 /// ```
-/// use rusty_parsec::*;
-/// 
-/// let expected = Ok(ParserSuccess::new(123, Position::new(1, 4, 3)));
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("any char in \"+-\""), 
+///     Some(String::from("2")),
+///     Position::new(1, 1, 0)
+/// ));
 /// 
-/// let actual = 
-///     p_u32().run("123abc".to_string());
+/// let actual = many_1_any_of("+-")
+///     .run(String::from("2+-"));
 /// 
 /// assert_eq!(actual, expected);
 /// ```
-pub fn p_i32() -> Parser<i32> {
-    p_int(Box::new(|maybe_int: String| maybe_int.parse::<i32>()))
+pub fn many_1_any_of(chars: &str) -> Parser<String> {
+    let (f, label) = any_of_class(chars);
+    many_char_class(f, label, true)
 }
 
-/// `p_i64` tries to parse the input string as an integer and if it succeeds, returns the result as an i64 integer.
+/// `none_of` takes a string of characters (`chars`) and returns a parser. When the parser is applied to the input string, it will 
+/// return the character parsed as a `ParserSuccess` if the next character in the input string is not one of the characters in `chars`.
+/// 
+/// # Errors
+/// `none_of` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is one of the characters in `chars`
+/// or if there is no input left to parse.
 /// 
 /// # Examples
 /// 
 /// ```
-/// use rusty_parsec::*;
-/// 
-/// let expected = Ok(ParserSuccess::new(123, Position::new(1, 4, 3)));
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("any char not in \"\\\"\\\\\""), 
+///     Some(String::from("\"")),
+///     Position::new(1, 1, 0)
+/// ));
 /// 
-/// let actual = 
-///     p_i64().run("123abc".to_string());
+/// let actual = none_of("\"\\")
+///     .run(String::from("\"abc"));
 /// 
 /// assert_eq!(actual, expected);
 /// ```
-pub fn p_i64() -> Parser<i64> {
-    p_int(Box::new(|maybe_int: String| maybe_int.parse::<i64>()))
+pub fn none_of(chars: &str) -> Parser<char> {
+    let (f, label) = none_of_class(chars);
+    char_class(f, label)
 }
 
-/// `p_u32` tries to parse the input string as an integer and if it succeeds, returns the result as an u32 integer.
+/// `many_none_of` works like `none_of`, but applies the parser repeatedly until the next character in the input string is one of the characters in `chars`.
+/// All successfully parsed characters are returned as a single string.
+/// 
+/// # Errors
+/// `many_none_of` will never return an error. If the first character is one of the characters in `chars`, it returns an empty string.
 /// 
 /// # Examples
 /// 
 /// ```
-/// use rusty_parsec::*;
-/// 
-/// let expected = Ok(ParserSuccess::new(123, Position::new(1, 4, 3)));
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("abc"), 
+///     Position::new(1, 4, 3)
+/// ));
 /// 
-/// let actual = 
-///     p_u32().run("123abc".to_string());
+/// let actual = many_none_of("\"\\")
+///     .run(String::from("abc\""));
 /// 
 /// assert_eq!(actual, expected);
 /// ```
-pub fn p_u32() -> Parser<u32> {
-    p_int(Box::new(|maybe_int: String| maybe_int.parse::<u32>()))
+pub fn many_none_of(chars: &str) -> Parser<String> {
+    let (f, label) = none_of_class(chars);
+    many_char_class(f, label, false)
 }
 
-/// `p_u64` tries to parse the input string as an integer and if it succeeds, returns the result as an u64 integer.
+/// `many_1_none_of` works like `many_none_of`, except that at least one character must be parsed.
+/// 
+/// # Errors
+/// `many_1_none_of` will return a `ParserFailure` with a severity of `Error` if the first character is one of the characters in `chars`.
 /// 
 /// # Examples
 /// 
 /// ```
-/// use rusty_parsec::*;
-/// 
-/// let expected = Ok(ParserSuccess::new(123, Position::new(1, 4, 3)));
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("abc"), 
+///     Position::new(1, 4, 3)
+/// ));
 /// 
-/// let actual = 
-///     p_u64().run("123abc".to_string());
+/// let actual = many_1_none_of("\"")
+///     .run(String::from("abc\""));
 /// 
 /// assert_eq!(actual, expected);
 /// ```
-pub fn p_u64() -> Parser<u64> {
-    p_int(Box::new(|maybe_int: String| maybe_int.parse::<u64>()))
+pub fn many_1_none_of(chars: &str) -> Parser<String> {
+    let (f, label) = none_of_class(chars);
+    many_char_class(f, label, true)
 }
 
-/// `p_isize` tries to parse the input string as an integer and if it succeeds, returns the result as an isize integer.
+/// `char_range` takes an inclusive range of characters from `start` to `end` and returns a parser. When the parser is applied to the input string, it will 
+/// return the character parsed as a `ParserSuccess` if the next character in the input string falls within the range.
+/// 
+/// # Errors
+/// `char_range` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is outside of the range.
 /// 
 /// # Examples
 /// 
 /// ```
-/// use rusty_parsec::*;
-/// 
-/// let expected = Ok(ParserSuccess::new(123, Position::new(1, 4, 3)));
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("char in range 'a'-'z'"), 
+///     Some(String::from("A")),
+///     Position::new(1, 1, 0)
+/// ));
 /// 
-/// let actual = 
-///     p_isize().run("123abc".to_string());
+/// let actual = char_range('a', 'z')
+///     .run(String::from("Abc"));
 /// 
 /// assert_eq!(actual, expected);
 /// ```
-pub fn p_isize() -> Parser<isize> {
-    p_int(Box::new(|maybe_int: String| maybe_int.parse::<isize>()))
+pub fn char_range(start: char, end: char) -> Parser<char> {
+    let (f, label) = char_range_class(start, end);
+    char_class(f, label)
 }
 
-/// `p_usize` tries to parse the input string as an integer and if it succeeds, returns the result as an usize integer.
+/// `many_char_range` works like `char_range`, but applies the parser repeatedly until the next character in the input string is outside of the range.
+/// All successfully parsed characters are returned as a single string.
+/// 
+/// # Errors
+/// `many_char_range` will never return an error. If the first character is outside of the range, it returns an empty string.
 /// 
 /// # Examples
 /// 
 /// ```
-/// use rusty_parsec::*;
-/// 
-/// let expected = Ok(ParserSuccess::new(123, Position::new(1, 4, 3)));
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("abc"), 
+///     Position::new(1, 4, 3)
+/// ));
 /// 
-/// let actual = 
-///     p_usize().run("123abc".to_string());
+/// let actual = many_char_range('a', 'z')
+///     .run(String::from("abcDEF"));
 /// 
 /// assert_eq!(actual, expected);
 /// ```
-pub fn p_usize() -> Parser<usize> {
-    p_int(Box::new(|maybe_int: String| maybe_int.parse::<usize>()))
+pub fn many_char_range(start: char, end: char) -> Parser<String> {
+    let (f, label) = char_range_class(start, end);
+    many_char_class(f, label, false)
 }
 
-fn p_int<T>(parse_num: Box<dyn Fn(String) -> Result<T, std::num::ParseIntError>>) -> Parser<T> 
-where T: PrimInt + 'static
-{
-    let parser_fn =
-        Box::new(
-            move |state: &mut ParserState| {
-                let mut count = 0;
-
-                for c in state.get_remaining_input().chars() {
-                    if c.is_numeric() || c == '-' && count == 0 {
-                        count += c.len_utf8();
-                    } else {
-                        break;
-                    }
-                }
-
-                match state.get_slice(count).map(|s| parse_num(s)) {
-                    Some(Ok(int)) => {
-                        state.move_state_forward(count);
-                        Ok(ParserSuccess::new(int, state.get_position()))
-                    },
-                    _ =>
-                        Err(ParserFailure::new_err(
-                            "integral value".to_string(),
-                            None,
-                            state.get_position())
-                        ),
-                }
-            }
-        );
-
-    Parser::new(parser_fn)
+/// `many_1_char_range` works like `many_char_range`, except that at least one character must be parsed.
+/// 
+/// # Errors
+/// `many_1_char_range` will return a `ParserFailure` with a severity of `Error` if the first character is outside of the range.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("abc"), 
+///     Position::new(1, 4, 3)
+/// ));
+/// 
+/// let actual = many_1_char_range('a', 'z')
+///     .run(String::from("abcDEF"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_1_char_range(start: char, end: char) -> Parser<String> {
+    let (f, label) = char_range_class(start, end);
+    many_char_class(f, label, true)
 }
 
-/// `p_f32` tries to parse the input string as a floating point number and if it succeeds, returns the result as an f32 floating point.
+/// `letter` parses a single Unicode alphabetic character, returning the character parsed as a `ParserSuccess`.
+/// 
+/// # Errors
+/// `letter` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not a letter.
 /// 
 /// # Examples
 /// 
 /// ```
-/// use rusty_parsec::*;
-/// 
-/// let expected = Ok(ParserSuccess::new(123.35, Position::new(1, 7, 6)));
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     'é', 
+///     Position::new(1, 3, 2)
+/// ));
 /// 
-/// let actual = 
-///     p_f32().run("123.35abc".to_string());
+/// let actual = letter()
+///     .run(String::from("été"));
 /// 
 /// assert_eq!(actual, expected);
 /// ```
-pub fn p_f32() -> Parser<f32> {
-    p_float(Box::new(|maybe_float: String| maybe_float.parse::<f32>()))
+pub fn letter() -> Parser<char> {
+    char_class(Box::new(char::is_alphabetic), "letter".to_string())
 }
 
-/// `p_f64` tries to parse the input string as a floating point number and if it succeeds, returns the result as an f64 floating point.
+/// `many_letter` parses zero or more successive Unicode alphabetic characters, returning them as a single string.
 /// 
 /// # Examples
 /// 
 /// ```
-/// use rusty_parsec::*;
-/// 
-/// let expected = Ok(ParserSuccess::new(123.35, Position::new(1, 7, 6)));
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("abc"), 
+///     Position::new(1, 4, 3)
+/// ));
 /// 
-/// let actual = 
-///     p_f64().run("123.35abc".to_string());
+/// let actual = many_letter()
+///     .run(String::from("abc123"));
 /// 
 /// assert_eq!(actual, expected);
 /// ```
-pub fn p_f64() -> Parser<f64> {
-    p_float(Box::new(|maybe_float: String| maybe_float.parse::<f64>()))
+pub fn many_letter() -> Parser<String> {
+    many_char_class(Box::new(char::is_alphabetic), "letter".to_string(), false)
 }
 
-fn p_float<T>(parse_num: Box<dyn Fn(String) -> Result<T, std::num::ParseFloatError>>) -> Parser<T> 
-where T: Float + 'static
-{
-    let parser_fn =
-        Box::new(
-            move |state: &mut ParserState| {
+/// `many_1_letter` parses one or more successive Unicode alphabetic characters, returning them as a single string.
+/// 
+/// # Errors
+/// `many_1_letter` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not a letter.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("letter"), 
+///     Some(String::from("1")),
+///     Position::new(1, 1, 0)
+/// ));
+/// 
+/// let actual = many_1_letter()
+///     .run(String::from("123abc"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_1_letter() -> Parser<String> {
+    many_char_class(Box::new(char::is_alphabetic), "letter".to_string(), true)
+}
+
+/// `digit` parses a single ASCII decimal digit (`0`-`9`), returning the character parsed as a `ParserSuccess`.
+/// 
+/// # Errors
+/// `digit` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not a digit.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     '7', 
+///     Position::new(1, 2, 1)
+/// ));
+/// 
+/// let actual = digit()
+///     .run(String::from("7a"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn digit() -> Parser<char> {
+    char_class(Box::new(|c: char| c.is_ascii_digit()), "digit".to_string())
+}
+
+/// `many_digit` parses zero or more successive ASCII decimal digits, returning them as a single string.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("2020"), 
+///     Position::new(1, 5, 4)
+/// ));
+/// 
+/// let actual = many_digit()
+///     .run(String::from("2020-01-01"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_digit() -> Parser<String> {
+    many_char_class(Box::new(|c: char| c.is_ascii_digit()), "digit".to_string(), false)
+}
+
+/// `many_1_digit` parses one or more successive ASCII decimal digits, returning them as a single string.
+/// 
+/// # Errors
+/// `many_1_digit` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not a digit.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("2020"), 
+///     Position::new(1, 5, 4)
+/// ));
+/// 
+/// let actual = many_1_digit()
+///     .run(String::from("2020-01-01"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_1_digit() -> Parser<String> {
+    many_char_class(Box::new(|c: char| c.is_ascii_digit()), "digit".to_string(), true)
+}
+
+/// `hex_digit` parses a single ASCII hexadecimal digit (`0`-`9`, `a`-`f`, `A`-`F`), returning the character parsed as a `ParserSuccess`.
+/// 
+/// # Errors
+/// `hex_digit` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not a hexadecimal digit.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     'F', 
+///     Position::new(1, 2, 1)
+/// ));
+/// 
+/// let actual = hex_digit()
+///     .run(String::from("FF"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn hex_digit() -> Parser<char> {
+    char_class(Box::new(|c: char| c.is_ascii_hexdigit()), "hex digit".to_string())
+}
+
+/// `many_hex_digit` parses zero or more successive ASCII hexadecimal digits, returning them as a single string.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("ff00A0"), 
+///     Position::new(1, 7, 6)
+/// ));
+/// 
+/// let actual = many_hex_digit()
+///     .run(String::from("ff00A0;"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_hex_digit() -> Parser<String> {
+    many_char_class(Box::new(|c: char| c.is_ascii_hexdigit()), "hex digit".to_string(), false)
+}
+
+/// `many_1_hex_digit` parses one or more successive ASCII hexadecimal digits, returning them as a single string.
+/// 
+/// # Errors
+/// `many_1_hex_digit` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not a hexadecimal digit.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("hex digit"), 
+///     Some(String::from("g")),
+///     Position::new(1, 1, 0)
+/// ));
+/// 
+/// let actual = many_1_hex_digit()
+///     .run(String::from("gg"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_1_hex_digit() -> Parser<String> {
+    many_char_class(Box::new(|c: char| c.is_ascii_hexdigit()), "hex digit".to_string(), true)
+}
+
+/// `alphanumeric` parses a single Unicode alphabetic or numeric character, returning the character parsed as a `ParserSuccess`.
+/// 
+/// # Errors
+/// `alphanumeric` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not alphanumeric.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     '1', 
+///     Position::new(1, 2, 1)
+/// ));
+/// 
+/// let actual = alphanumeric()
+///     .run(String::from("1a"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn alphanumeric() -> Parser<char> {
+    char_class(Box::new(char::is_alphanumeric), "alphanumeric char".to_string())
+}
+
+/// `many_alphanumeric` parses zero or more successive Unicode alphabetic or numeric characters, returning them as a single string.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("abc123"), 
+///     Position::new(1, 7, 6)
+/// ));
+/// 
+/// let actual = many_alphanumeric()
+///     .run(String::from("abc123!"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_alphanumeric() -> Parser<String> {
+    many_char_class(Box::new(char::is_alphanumeric), "alphanumeric char".to_string(), false)
+}
+
+/// `many_1_alphanumeric` parses one or more successive Unicode alphabetic or numeric characters, returning them as a single string.
+/// 
+/// # Errors
+/// `many_1_alphanumeric` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not alphanumeric.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("alphanumeric char"), 
+///     Some(String::from("!")),
+///     Position::new(1, 1, 0)
+/// ));
+/// 
+/// let actual = many_1_alphanumeric()
+///     .run(String::from("!abc123"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_1_alphanumeric() -> Parser<String> {
+    many_char_class(Box::new(char::is_alphanumeric), "alphanumeric char".to_string(), true)
+}
+
+/// `upper` parses a single Unicode uppercase character, returning the character parsed as a `ParserSuccess`.
+/// 
+/// # Errors
+/// `upper` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not uppercase.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     'A', 
+///     Position::new(1, 2, 1)
+/// ));
+/// 
+/// let actual = upper()
+///     .run(String::from("Abc"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn upper() -> Parser<char> {
+    char_class(Box::new(char::is_uppercase), "uppercase letter".to_string())
+}
+
+/// `many_upper` parses zero or more successive Unicode uppercase characters, returning them as a single string.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("ABC"), 
+///     Position::new(1, 4, 3)
+/// ));
+/// 
+/// let actual = many_upper()
+///     .run(String::from("ABCdef"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_upper() -> Parser<String> {
+    many_char_class(Box::new(char::is_uppercase), "uppercase letter".to_string(), false)
+}
+
+/// `many_1_upper` parses one or more successive Unicode uppercase characters, returning them as a single string.
+/// 
+/// # Errors
+/// `many_1_upper` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not uppercase.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("uppercase letter"), 
+///     Some(String::from("a")),
+///     Position::new(1, 1, 0)
+/// ));
+/// 
+/// let actual = many_1_upper()
+///     .run(String::from("abc"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_1_upper() -> Parser<String> {
+    many_char_class(Box::new(char::is_uppercase), "uppercase letter".to_string(), true)
+}
+
+/// `lower` parses a single Unicode lowercase character, returning the character parsed as a `ParserSuccess`.
+/// 
+/// # Errors
+/// `lower` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not lowercase.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     'a', 
+///     Position::new(1, 2, 1)
+/// ));
+/// 
+/// let actual = lower()
+///     .run(String::from("aBC"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn lower() -> Parser<char> {
+    char_class(Box::new(char::is_lowercase), "lowercase letter".to_string())
+}
+
+/// `many_lower` parses zero or more successive Unicode lowercase characters, returning them as a single string.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("abc"), 
+///     Position::new(1, 4, 3)
+/// ));
+/// 
+/// let actual = many_lower()
+///     .run(String::from("abcDEF"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_lower() -> Parser<String> {
+    many_char_class(Box::new(char::is_lowercase), "lowercase letter".to_string(), false)
+}
+
+/// `many_1_lower` parses one or more successive Unicode lowercase characters, returning them as a single string.
+/// 
+/// # Errors
+/// `many_1_lower` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not lowercase.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("lowercase letter"), 
+///     None,
+///     Position::new(1, 1, 0)
+/// ));
+/// 
+/// let actual = many_1_lower()
+///     .run(String::new());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_1_lower() -> Parser<String> {
+    many_char_class(Box::new(char::is_lowercase), "lowercase letter".to_string(), true)
+}
+
+/// `unicode_category` takes a Unicode general category and returns a parser. When the parser is applied to the input string, it will 
+/// return the character parsed as a `ParserSuccess` if the next character in the input string belongs to the `category`.
+/// 
+/// # Errors
+/// `unicode_category` will return a `ParserFailure` with a severity of `Error` if the next character in the input string does not belong to the `category`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("char in unicode category CurrencySymbol"), 
+///     Some(String::from("a")),
+///     Position::new(1, 1, 0)
+/// ));
+/// 
+/// let actual = unicode_category(GeneralCategory::CurrencySymbol)
+///     .run(String::from("a€"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn unicode_category(category: GeneralCategory) -> Parser<char> {
+    let (f, label) = unicode_category_class(category);
+    char_class(f, label)
+}
+
+/// `many_unicode_category` parses zero or more successive characters belonging to the Unicode general `category`, returning them as a single string.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("ΑΒΓ"), 
+///     Position::new(1, 7, 6)
+/// ));
+/// 
+/// let actual = many_unicode_category(GeneralCategory::UppercaseLetter)
+///     .run(String::from("ΑΒΓδ"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_unicode_category(category: GeneralCategory) -> Parser<String> {
+    let (f, label) = unicode_category_class(category);
+    many_char_class(f, label, false)
+}
+
+/// `many_1_unicode_category` parses one or more successive characters belonging to the Unicode general `category`, returning them as a single string.
+/// 
+/// # Errors
+/// `many_1_unicode_category` will return a `ParserFailure` with a severity of `Error` if the next character in the input string does not belong to the `category`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("€$"), 
+///     Position::new(1, 5, 4)
+/// ));
+/// 
+/// let actual = many_1_unicode_category(GeneralCategory::CurrencySymbol)
+///     .run(String::from("€$100"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_1_unicode_category(category: GeneralCategory) -> Parser<String> {
+    let (f, label) = unicode_category_class(category);
+    many_char_class(f, label, true)
+}
+
+type CharClass = (Box<dyn Fn (char) -> bool>, String);
+
+fn any_of_class(chars: &str) -> CharClass {
+    let set = chars.to_string();
+    (Box::new(move |c: char| set.contains(c)), format!("any char in {:?}", chars))
+}
+
+fn none_of_class(chars: &str) -> CharClass {
+    let set = chars.to_string();
+    (Box::new(move |c: char| !set.contains(c)), format!("any char not in {:?}", chars))
+}
+
+fn char_range_class(start: char, end: char) -> CharClass {
+    (Box::new(move |c: char| start <= c && c <= end), format!("char in range '{}'-'{}'", start, end))
+}
+
+fn unicode_category_class(category: GeneralCategory) -> CharClass {
+    (Box::new(move |c: char| get_general_category(c) == category), format!("char in unicode category {:?}", category))
+}
+
+fn char_class(f: Box<dyn Fn (char) -> bool>, label: String) -> Parser<char> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                match state.get_remaining_input().chars().next() {
+                    Some(c) if f(c) => {
+                        state.move_state_forward(c.len_utf8())?;
+                        Ok(ParserSuccess::new(c, state.get_position()))
+                    },
+                    next => {
+                        Err(ParserFailure::new_err(
+                            label,
+                            next.map(|c| c.to_string()),
+                            state.get_position()
+                        ))
+                    },
+                }
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+fn many_char_class(f: Box<dyn Fn (char) -> bool>, label: String, at_least_one: bool) -> Parser<String> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let remaining_input = state.get_remaining_input();
+                let count = remaining_input
+                    .find(|c: char| !f(c))
+                    .unwrap_or(remaining_input.len());
+
+                if at_least_one && count == 0 {
+                    return Err(ParserFailure::new_err(
+                        label,
+                        remaining_input.chars().next().map(|c| c.to_string()),
+                        state.get_position()
+                    ))
+                }
+
+                let result = remaining_input[..count].to_string();
+                state.move_state_forward(count)?;
+                Ok(ParserSuccess::new(result, state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `p_string` takes a String as an argument and returns a parser success with the expected String value if the next string slice of the input string is a match, otherwise it returns a parser failure.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Ok(ParserSuccess::new("hello".to_string(), Position::new(1, 6, 5)));
+/// 
+/// let actual = 
+///     p_string("hello".to_string())
+///         .run("hello, world".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn p_string(target: String) -> Parser<String> {
+    string_return(target.clone(), target)
+}
+
+/// `skip_string` takes a String as an argument and returns a parser success of `()` if the next string slice of the input string is a match, otherwise it returns a parser failure.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Ok(ParserSuccess::new((), Position::new(1, 6, 5)));
+/// 
+/// let actual =  
+///     skip_string("hello".to_string())
+///         .run("hello, world".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn skip_string(target: String) -> Parser<()> {
+    string_return(target, ())
+}
+
+/// `string_return` takes a String as an argument and returns a parser success of the value supplied as the second argument of the function if the next string slice of the input string is a match, otherwise it returns a parser failure.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Ok(ParserSuccess::new(true, Position::new(1, 6, 5)));
+/// 
+/// let actual = 
+///     string_return("hello".to_string(), true)
+///         .run("hello, world".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn string_return<T>(target: String, return_value: T) -> Parser<T> 
+where T: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                match state.get_slice(target.len()) {
+                    Some(s) if s == target => {
+                        state.move_state_forward(target.len())?;
+                        Ok(ParserSuccess::new(return_value, state.get_position()))
+                    },
+                    Some(s) => {
+                        Err(ParserFailure::new_err(
+                            target,
+                            Some(s),
+                            state.get_position()
+                        ))
+                    },
+                    None => {
+                        let actual = slice_to_char_boundary(state.get_remaining_input(), target.len());
+
+                        Err(ParserFailure::new_err(
+                            target,
+                            actual,
+                            state.get_position()
+                        ))
+                    },
+                }
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `p_string_set` takes a slice of strings and returns a parser success with the longest string in the set that matches the next string slice 
+/// of the input string, otherwise it returns a parser failure. Unlike `choice`, the order of the strings in the set does not matter, and the
+/// input is only scanned once regardless of the number of strings in the set.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Ok(ParserSuccess::new("int".to_string(), Position::new(1, 4, 3)));
+/// 
+/// let actual = 
+///     p_string_set(&["in", "int", "interface"])
+///         .run("int x".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn p_string_set(targets: &[&str]) -> Parser<String> {
+    StringSet::from_strings(targets).parser()
+}
+
+/// `string_set_return` takes a slice of string and value pairs and returns a parser success of the value paired with the longest string 
+/// in the set that matches the next string slice of the input string, otherwise it returns a parser failure.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Ok(ParserSuccess::new(2, Position::new(1, 3, 2)));
+/// 
+/// let actual = 
+///     string_set_return(&[("<", 1), ("<=", 2), ("<<", 3)])
+///         .run("<=5".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn string_set_return<T>(targets: &[(&str, T)]) -> Parser<T> 
+where T: Clone + 'static
+{
+    StringSet::new(targets).parser()
+}
+
+/// `p_keyword_set` works like `p_string_set`, except that a string in the set only matches if it is not immediately followed by a letter,
+/// a digit, or an underscore.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Err(ParserFailure::new_err(
+///     "one of [\"in\", \"int\", \"interface\"]".to_string(),
+///     Some("interface".to_string()),
+///     Position::new(1, 1, 0)
+/// ));
+/// 
+/// let actual = 
+///     p_keyword_set(&["in", "int", "interface"])
+///         .run("interface1".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn p_keyword_set(targets: &[&str]) -> Parser<String> {
+    StringSet::from_strings(targets).keyword_parser()
+}
+
+/// `keyword_set_return` works like `string_set_return`, except that a string in the set only matches if it is not immediately followed by 
+/// a letter, a digit, or an underscore.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Ok(ParserSuccess::new(false, Position::new(1, 6, 5)));
+/// 
+/// let actual = 
+///     keyword_set_return(&[("true", true), ("false", false)])
+///         .run("false)".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn keyword_set_return<T>(targets: &[(&str, T)]) -> Parser<T> 
+where T: Clone + 'static
+{
+    StringSet::new(targets).keyword_parser()
+}
+
+/// `StringSet` holds a set of strings and the value paired with each of them in a trie, so that the parsers it returns can be applied many times,
+/// for example by a recursive `fn() -> Parser<T>` or inside of `many`, without building the trie again. Cloning a `StringSet` is cheap, as every
+/// clone shares the same trie. `p_string_set`, `string_set_return`, `p_keyword_set` and `keyword_set_return` build a new `StringSet` each time they are
+/// called, so a `StringSet` should be used instead whenever the same set of strings is parsed repeatedly.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// thread_local! {
+///     static OPERATORS: StringSet<u32> = StringSet::new(&[("<", 1), ("<=", 2), ("<<", 3)]);
+/// }
+/// 
+/// fn p_operator() -> Parser<u32> {
+///     OPERATORS.with(|operators| operators.parser())
+/// }
+/// 
+/// let expected = Ok(ParserSuccess::new(vec![3, 2, 1], Position::new(1, 6, 5)));
+/// 
+/// let actual = 
+///     many(p_operator)
+///         .run("<<<=<".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+#[derive(Clone)]
+pub struct StringSet<T> {
+    set: Rc<StringSetEntries<T>>,
+}
+
+struct StringSetEntries<T> {
+    trie: Trie,
+    values: Vec<T>,
+    label: String,
+    max_chars: usize,
+}
+
+impl<T> StringSet<T> 
+where T: Clone + 'static
+{
+    /// `new` creates a `StringSet` from a slice of string and value pairs. If the same string appears more than once, the first value is kept.
+    pub fn new(targets: &[(&str, T)]) -> StringSet<T> {
+        let keys: Vec<&str> = targets.iter().map(|(key, _)| *key).collect();
+
+        let set = StringSetEntries {
+            trie: Trie::new(&keys),
+            values: targets.iter().map(|(_, value)| value.clone()).collect(),
+            label: format!("one of {:?}", keys),
+            max_chars: keys.iter().map(|key| key.chars().count()).max().unwrap_or(0),
+        };
+
+        StringSet { set: Rc::new(set) }
+    }
+
+    /// `parser` returns a parser that works like `string_set_return`, returning the value paired with the longest string in the set that matches
+    /// the next string slice of the input string.
+    pub fn parser(&self) -> Parser<T> {
+        self.set_parser(false)
+    }
+
+    /// `keyword_parser` returns a parser that works like `keyword_set_return`, returning the value paired with the longest string in the set that matches
+    /// the next string slice of the input string and is not immediately followed by a letter, a digit, or an underscore.
+    pub fn keyword_parser(&self) -> Parser<T> {
+        self.set_parser(true)
+    }
+
+    fn set_parser(&self, word_boundary: bool) -> Parser<T> {
+        let set = Rc::clone(&self.set);
+
+        let parser_fn =
+            Box::new(
+                move |state: &mut ParserState| {
+                    let accept = |rest: &str| !word_boundary || is_word_boundary(rest);
+                    let remaining_input = state.get_remaining_input();
+
+                    match set.trie.longest_match(remaining_input, accept) {
+                        Some((length, index)) => {
+                            state.move_state_forward(length)?;
+                            Ok(ParserSuccess::new(set.values[index].clone(), state.get_position()))
+                        },
+                        None => {
+                            let actual: String = remaining_input.chars().take(set.max_chars.max(1)).collect();
+
+                            Err(ParserFailure::new_err(
+                                set.label.clone(),
+                                if actual.is_empty() { None } else { Some(actual) },
+                                state.get_position()
+                            ))
+                        },
+                    }
+                }
+            );
+
+        Parser::new(parser_fn)
+    }
+}
+
+impl StringSet<String> {
+    /// `from_strings` creates a `StringSet` from a slice of strings, where each string is paired with itself.
+    pub fn from_strings(targets: &[&str]) -> StringSet<String> {
+        let targets: Vec<(&str, String)> = targets.iter().map(|target| (*target, target.to_string())).collect();
+        StringSet::new(&targets)
+    }
+}
+
+fn is_word_boundary(input: &str) -> bool {
+    match input.chars().next() {
+        Some(c) => !(c.is_alphanumeric() || c == '_'),
+        None => true,
+    }
+}
+
+/// `IdentifierOptions` describes what `identifier` accepts. `is_start` decides which characters may begin an identifier and `is_continue`
+/// which characters may follow the first one. When `normalize` is true the identifier is converted to Unicode Normalization Form C before
+/// it is checked against the `reserved_words` and returned.
+/// 
+/// The default options follow the Unicode XID rules used by Rust: an identifier starts with an `XID_Start` character or an underscore,
+/// continues with `XID_Continue` characters, is not normalised, and has no reserved words.
+#[derive(Clone, Debug)]
+pub struct IdentifierOptions {
+    pub is_start: fn(char) -> bool,
+    pub is_continue: fn(char) -> bool,
+    pub normalize: bool,
+    pub reserved_words: Vec<&'static str>,
+}
+
+impl Default for IdentifierOptions {
+    fn default() -> IdentifierOptions {
+        IdentifierOptions {
+            is_start: |c: char| c == '_' || UnicodeXID::is_xid_start(c),
+            is_continue: UnicodeXID::is_xid_continue,
+            normalize: false,
+            reserved_words: Vec::new(),
+        }
+    }
+}
+
+/// `identifier` parses a single identifier as described by the `options` and returns it as a String.
+/// 
+/// # Errors
+/// `identifier` will return a `ParserFailure` with a severity of `Error` if the next character in the input string cannot start an identifier,
+/// or if the identifier parsed is one of the `reserved_words`. In both cases the parser state is left unchanged and the failure is reported at
+/// the position where the identifier would have started.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let options = IdentifierOptions {
+///     reserved_words: vec!["if", "else"],
+///     ..IdentifierOptions::default()
+/// };
+/// 
+/// let expected = Ok(ParserSuccess::new("ifdef".to_string(), Position::new(1, 6, 5)));
+/// 
+/// let actual = 
+///     identifier(options.clone())
+///         .run("ifdef".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// 
+/// 
+/// let expected = Err(ParserFailure::new_err(
+///     "identifier".to_string(),
+///     Some("keyword 'if'".to_string()),
+///     Position::new(1, 1, 0)
+/// ));
+/// 
+/// let actual = 
+///     identifier(options)
+///         .run("if x".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn identifier(options: IdentifierOptions) -> Parser<String> {
+    let reserved_words: HashSet<&str> = options.reserved_words.iter().copied().collect();
+
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let remaining_input = state.get_remaining_input();
+                let mut chars = remaining_input.chars();
+
+                let first = match chars.next() {
+                    Some(c) if (options.is_start)(c) => c,
+                    next => {
+                        return Err(ParserFailure::new_err(
+                            "identifier".to_string(),
+                            next.map(|c| c.to_string()),
+                            state.get_position()
+                        ))
+                    },
+                };
+
+                let count = first.len_utf8() + chars
+                    .take_while(|c| (options.is_continue)(*c))
+                    .map(char::len_utf8)
+                    .sum::<usize>();
+
+                let result = 
+                    if options.normalize {
+                        remaining_input[..count].nfc().collect()
+                    } else {
+                        remaining_input[..count].to_string()
+                    };
+
+                if reserved_words.contains(result.as_str()) {
+                    return Err(ParserFailure::new_err(
+                        "identifier".to_string(),
+                        Some(format!("keyword '{}'", result)),
+                        state.get_position()
+                    ))
+                }
+
+                state.move_state_forward(count)?;
+                Ok(ParserSuccess::new(result, state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `p_i32` tries to parse the input string as an integer and if it succeeds, returns the result as an i32 integer.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Ok(ParserSuccess::new(123, Position::new(1, 4, 3)));
+/// 
+/// let actual = 
+///     p_u32().run("123abc".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn p_i32() -> Parser<i32> {
+    p_int(Box::new(|maybe_int: String| maybe_int.parse::<i32>()))
+}
+
+/// `p_i64` tries to parse the input string as an integer and if it succeeds, returns the result as an i64 integer.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Ok(ParserSuccess::new(123, Position::new(1, 4, 3)));
+/// 
+/// let actual = 
+///     p_i64().run("123abc".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn p_i64() -> Parser<i64> {
+    p_int(Box::new(|maybe_int: String| maybe_int.parse::<i64>()))
+}
+
+/// `p_u32` tries to parse the input string as an integer and if it succeeds, returns the result as an u32 integer.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Ok(ParserSuccess::new(123, Position::new(1, 4, 3)));
+/// 
+/// let actual = 
+///     p_u32().run("123abc".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn p_u32() -> Parser<u32> {
+    p_int(Box::new(|maybe_int: String| maybe_int.parse::<u32>()))
+}
+
+/// `p_u64` tries to parse the input string as an integer and if it succeeds, returns the result as an u64 integer.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Ok(ParserSuccess::new(123, Position::new(1, 4, 3)));
+/// 
+/// let actual = 
+///     p_u64().run("123abc".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn p_u64() -> Parser<u64> {
+    p_int(Box::new(|maybe_int: String| maybe_int.parse::<u64>()))
+}
+
+/// `p_isize` tries to parse the input string as an integer and if it succeeds, returns the result as an isize integer.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Ok(ParserSuccess::new(123, Position::new(1, 4, 3)));
+/// 
+/// let actual = 
+///     p_isize().run("123abc".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn p_isize() -> Parser<isize> {
+    p_int(Box::new(|maybe_int: String| maybe_int.parse::<isize>()))
+}
+
+/// `p_usize` tries to parse the input string as an integer and if it succeeds, returns the result as an usize integer.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Ok(ParserSuccess::new(123, Position::new(1, 4, 3)));
+/// 
+/// let actual = 
+///     p_usize().run("123abc".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn p_usize() -> Parser<usize> {
+    p_int(Box::new(|maybe_int: String| maybe_int.parse::<usize>()))
+}
+
+fn p_int<T>(parse_num: Box<dyn Fn(String) -> Result<T, std::num::ParseIntError>>) -> Parser<T> 
+where T: PrimInt + 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let mut count = 0;
+
+                for c in state.get_remaining_input().chars() {
+                    if c.is_numeric() || c == '-' && count == 0 {
+                        count += c.len_utf8();
+                    } else {
+                        break;
+                    }
+                }
+
+                match state.get_slice(count).map(&parse_num) {
+                    Some(Ok(int)) => {
+                        state.move_state_forward(count)?;
+                        Ok(ParserSuccess::new(int, state.get_position()))
+                    },
+                    _ =>
+                        Err(ParserFailure::new_err(
+                            "integral value".to_string(),
+                            None,
+                            state.get_position())
+                        ),
+                }
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `p_f32` tries to parse the input string as a floating point number and if it succeeds, returns the result as an f32 floating point.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Ok(ParserSuccess::new(123.35, Position::new(1, 7, 6)));
+/// 
+/// let actual = 
+///     p_f32().run("123.35abc".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn p_f32() -> Parser<f32> {
+    p_float(Box::new(|maybe_float: String| maybe_float.parse::<f32>()))
+}
+
+/// `p_f64` tries to parse the input string as a floating point number and if it succeeds, returns the result as an f64 floating point.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let expected = Ok(ParserSuccess::new(123.35, Position::new(1, 7, 6)));
+/// 
+/// let actual = 
+///     p_f64().run("123.35abc".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn p_f64() -> Parser<f64> {
+    p_float(Box::new(|maybe_float: String| maybe_float.parse::<f64>()))
+}
+
+fn p_float<T>(parse_num: Box<dyn Fn(String) -> Result<T, std::num::ParseFloatError>>) -> Parser<T> 
+where T: Float + 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
                 let mut count = 0;
                 let mut has_decimal_point = false;
 
                 for c in state.get_remaining_input().chars() {
                     if c.is_numeric() || c == '-' && count == 0 {
                         count += c.len_utf8();
-                    } else if c == '.' && has_decimal_point == false {
+                    } else if c == '.' && !has_decimal_point {
                         has_decimal_point = true;
                         count += c.len_utf8();
                     } else {
@@ -479,9 +1660,9 @@ where T: Float + 'static
                     }
                 }
 
-                match state.get_slice(count).map(|s| parse_num(s)) {
+                match state.get_slice(count).map(&parse_num) {
                     Some(Ok(float)) if float.is_finite() => {
-                        state.move_state_forward(count);
+                        state.move_state_forward(count)?;
                         Ok(ParserSuccess::new(float, state.get_position()))
                     },
                     _ =>
@@ -528,7 +1709,247 @@ pub fn ws() -> Parser<()> {
                     }
                 }
 
-                state.move_state_forward(count);
+                state.move_state_forward(count)?;
+                Ok(ParserSuccess::new((), state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+
+/// `WhitespaceConfig` describes what `ws_with` treats as whitespace. An empty `line_comment` or an empty opening `block_comment`
+/// disables that kind of comment. When `nested` is true, block comments may contain other block comments, and when 
+/// `unicode_whitespace` is true every Unicode whitespace character is skipped instead of only ASCII whitespace.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct WhitespaceConfig {
+    pub line_comment: &'static str,
+    pub block_comment: (&'static str, &'static str),
+    pub nested: bool,
+    pub unicode_whitespace: bool,
+}
+
+/// `ws_with` parses zero or more successive whitespace characters and comments, as described by the `config`, returning `()` as the parser result.
+/// 
+/// # Errors
+/// `ws_with` will return a `ParserFailure` if a block comment is not terminated. The position of the failure is the position of the
+/// comment's opening delimiter, and the failure will be an `Error` if nothing was consumed before the comment, otherwise a `FatalError`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusty_parsec::*;
+/// 
+/// let config = WhitespaceConfig {
+///     line_comment: "//",
+///     block_comment: ("/*", "*/"),
+///     nested: true,
+///     unicode_whitespace: true,
+/// };
+/// 
+/// let expected = Ok(ParserSuccess::new('a', Position::new(2, 26, 36)));
+/// 
+/// let actual = 
+///     ws_with(config).take_next(p_char('a'))
+///         .run("// comment\n /* outer /* inner */ */a".to_string());
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn ws_with(config: WhitespaceConfig) -> Parser<()> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let mut consumed = false;
+
+                loop {
+                    match whitespace_length(state.get_remaining_input(), &config) {
+                        Some(0) => break,
+                        Some(count) => {
+                            state.move_state_forward(count)?;
+                            consumed = true;
+                        },
+                        None => {
+                            let expected = format!("{:?} to close block comment", config.block_comment.1);
+
+                            return if consumed {
+                                Err(ParserFailure::new_fatal_err(expected, None, state.get_position()))
+                            } else {
+                                Err(ParserFailure::new_err(expected, None, state.get_position()))
+                            }
+                        },
+                    }
+                }
+
+                Ok(ParserSuccess::new((), state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+fn whitespace_length(input: &str, config: &WhitespaceConfig) -> Option<usize> {
+    let (open, close) = config.block_comment;
+
+    if !config.line_comment.is_empty() && input.starts_with(config.line_comment) {
+        let (line_length, _) = line_length(input);
+        Some(line_length)
+    } else if !open.is_empty() && input.starts_with(open) {
+        block_comment_length(input, open, close, config.nested)
+    } else {
+        let is_whitespace = 
+            if config.unicode_whitespace { char::is_whitespace } else { |c: char| c.is_ascii_whitespace() };
+
+        Some(input.find(|c: char| !is_whitespace(c)).unwrap_or(input.len()))
+    }
+}
+
+fn block_comment_length(input: &str, open: &str, close: &str, nested: bool) -> Option<usize> {
+    let mut depth = 1;
+    let mut index = open.len();
+
+    while let Some(c) = input[index..].chars().next() {
+        let rest = &input[index..];
+
+        if rest.starts_with(close) {
+            depth -= 1;
+            index += close.len();
+
+            if depth == 0 {
+                return Some(index)
+            }
+        } else if nested && rest.starts_with(open) {
+            depth += 1;
+            index += open.len();
+        } else {
+            index += c.len_utf8();
+        }
+    }
+
+    None
+}
+
+/// `newline` parses a single newline, which can be any of `"\n"`, `"\r\n"`, or `"\r"`, and returns it normalised to `'\n'`.
+/// 
+/// # Errors
+/// `newline` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not a newline.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     ('a', '\n'), 
+///     Position::new(2, 1, 3)
+/// ));
+/// 
+/// let actual = p_char('a')
+///     .and(newline())
+///     .run(String::from("a\r\nb"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn newline() -> Parser<char> {
+    newline_return(false, "newline".to_string())
+}
+
+/// `unicode_newline` works like `newline`, but also accepts the Unicode line separators `'\u{0085}'`, `'\u{2028}'`, and `'\u{2029}'`,
+/// all of which are returned normalised to `'\n'`. Only `"\n"`, `"\r\n"`, and `"\r"` advance the line number of the parser position.
+/// 
+/// # Errors
+/// `unicode_newline` will return a `ParserFailure` with a severity of `Error` if the next character in the input string is not a newline.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     '\n', 
+///     Position::new(1, 4, 3)
+/// ));
+/// 
+/// let actual = unicode_newline()
+///     .run(String::from("\u{2028}abc"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn unicode_newline() -> Parser<char> {
+    newline_return(true, "unicode newline".to_string())
+}
+
+/// `rest_of_line` parses every character up to the next newline or the end of the input, returning the characters parsed as a String.
+/// If `skip_newline` is true the newline is consumed as well, but it is never included in the result.
+/// 
+/// # Errors
+/// `rest_of_line` will never return an error. If the parser is already at a newline or at the end of the input, it returns an empty string.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     (String::from("// comment"), 'x'), 
+///     Position::new(2, 2, 12)
+/// ));
+/// 
+/// let actual = rest_of_line(true)
+///     .and(p_char('x'))
+///     .run(String::from("// comment\nx"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn rest_of_line(skip_newline: bool) -> Parser<String> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let remaining_input = state.get_remaining_input();
+                let (line_length, newline_length) = line_length(remaining_input);
+                let result = remaining_input[..line_length].to_string();
+
+                if skip_newline {
+                    state.move_state_forward(line_length + newline_length)?;
+                } else {
+                    state.move_state_forward(line_length)?;
+                }
+
+                Ok(ParserSuccess::new(result, state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `skip_rest_of_line` works like `rest_of_line`, but returns `()` as the parser result instead of the characters parsed.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     (), 
+///     Position::new(1, 11, 10)
+/// ));
+/// 
+/// let actual = skip_rest_of_line(false)
+///     .run(String::from("// comment\r\nx"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn skip_rest_of_line(skip_newline: bool) -> Parser<()> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let (line_length, newline_length) = line_length(state.get_remaining_input());
+
+                if skip_newline {
+                    state.move_state_forward(line_length + newline_length)?;
+                } else {
+                    state.move_state_forward(line_length)?;
+                }
+
                 Ok(ParserSuccess::new((), state.get_position()))
             }
         );
@@ -536,3 +1957,104 @@ pub fn ws() -> Parser<()> {
     Parser::new(parser_fn)
 }
 
+/// `eof` succeeds with `()` as the parser result only if the entire input string has been parsed.
+/// 
+/// # Errors
+/// `eof` will return a `ParserFailure` with a severity of `Error` if there is any input left to parse.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_fatal_err(
+///     String::from("end of input"), 
+///     Some(String::from("!")),
+///     Position::new(1, 6, 5)
+/// ));
+/// 
+/// let actual = p_string(String::from("hello"))
+///     .take_prev(eof())
+///     .run(String::from("hello!"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn eof() -> Parser<()> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                match state.get_remaining_input().chars().next() {
+                    None => Ok(ParserSuccess::new((), state.get_position())),
+                    Some(c) => {
+                        Err(ParserFailure::new_err(
+                            "end of input".to_string(),
+                            Some(c.to_string()),
+                            state.get_position()
+                        ))
+                    },
+                }
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+fn newline_return(include_unicode: bool, label: String) -> Parser<char> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let remaining_input = state.get_remaining_input();
+
+                match newline_length(remaining_input, include_unicode) {
+                    Some(length) => {
+                        state.move_state_forward(length)?;
+                        Ok(ParserSuccess::new('\n', state.get_position()))
+                    },
+                    None => {
+                        Err(ParserFailure::new_err(
+                            label,
+                            remaining_input.chars().next().map(|c| c.to_string()),
+                            state.get_position()
+                        ))
+                    },
+                }
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+fn newline_length(input: &str, include_unicode: bool) -> Option<usize> {
+    let mut chars = input.chars();
+
+    match chars.next() {
+        Some('\r') if chars.next() == Some('\n') => Some(2),
+        Some('\r') | Some('\n') => Some(1),
+        Some(c @ '\u{0085}') | Some(c @ '\u{2028}') | Some(c @ '\u{2029}') if include_unicode => Some(c.len_utf8()),
+        _ => None,
+    }
+}
+
+/// `slice_to_char_boundary` returns the start of the input up to the first character boundary at or after `length` bytes. This is used to report
+/// the actual input when a string slice of `length` bytes would end inside of a multi-byte character, and returns `None` if the input is shorter than `length`.
+fn slice_to_char_boundary(input: &str, length: usize) -> Option<String> {
+    if input.len() < length {
+        return None
+    }
+
+    let end = (length..=input.len())
+        .find(|index| input.is_char_boundary(*index))
+        .unwrap_or(input.len());
+
+    Some(input[..end].to_string())
+}
+
+fn line_length(input: &str) -> (usize, usize) {
+    let line_length = input
+        .find(['\n', '\r'])
+        .unwrap_or(input.len());
+
+    let newline_length = newline_length(&input[line_length..], false).unwrap_or(0);
+
+    (line_length, newline_length)
+}
@@ -0,0 +1,154 @@
+/// `alt!` applies each parser in sequence until one of the parsers returns a `ParserSuccess`. Unlike `choice`, the parsers may return 
+/// values of different types. Each parser can be followed by `=>` and a constructor function (such as a tuple variant of a user defined enum) 
+/// which is used to map the value of the successful parser into a shared type. When no constructor functions are given, `alt!` accepts 
+/// between two and eight parsers and returns the value of the successful parser in the matching arm of the `Alt2` to `Alt8` enums.
+/// 
+/// # Errors
+/// `alt!` follows the same rules as `or`. If a parser fails without changing the parser state, the next parser is applied.
+/// If a parser fails after changing the parser state, the `FatalError` is returned without applying the remaining parsers. 
+/// If all parsers fail, the `ParserFailure` of the last parser is returned.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// #[derive(Debug, PartialEq)]
+/// enum Token {
+///     Number(u32),
+///     Bool(bool),
+///     Name(String),
+/// }
+/// 
+/// let expected = Ok(ParserSuccess::new(
+///     Token::Bool(true),
+///     Position::new(1, 5, 4)
+/// ));
+/// 
+/// let actual = alt!(
+///     p_u32() => Token::Number,
+///     p_string(String::from("true")).then_return(true) => Token::Bool,
+///     many_satisfy(Box::new(|c: char| c.is_alphabetic())) => Token::Name,
+/// ).run(String::from("true"));
+/// 
+/// assert_eq!(actual, expected);
+/// 
+/// 
+/// let expected = Ok(ParserSuccess::new(
+///     Alt3::B(42),
+///     Position::new(1, 3, 2)
+/// ));
+/// 
+/// let actual = alt!(
+///     p_char('x'),
+///     p_u32(),
+///     p_string(String::from("true")),
+/// ).run(String::from("42"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+#[macro_export]
+macro_rules! alt {
+    ($p:expr => $f:expr $(, $ps:expr => $fs:expr)+ $(,)?) => {
+        $p.map(::std::boxed::Box::new($f))
+            $(.or($ps.map(::std::boxed::Box::new($fs))))+
+    };
+    ($p1:expr, $p2:expr $(,)?) => {
+        $crate::alt!($p1 => $crate::Alt2::A, $p2 => $crate::Alt2::B)
+    };
+    ($p1:expr, $p2:expr, $p3:expr $(,)?) => {
+        $crate::alt!($p1 => $crate::Alt3::A, $p2 => $crate::Alt3::B, $p3 => $crate::Alt3::C)
+    };
+    ($p1:expr, $p2:expr, $p3:expr, $p4:expr $(,)?) => {
+        $crate::alt!($p1 => $crate::Alt4::A, $p2 => $crate::Alt4::B, $p3 => $crate::Alt4::C, $p4 => $crate::Alt4::D)
+    };
+    ($p1:expr, $p2:expr, $p3:expr, $p4:expr, $p5:expr $(,)?) => {
+        $crate::alt!($p1 => $crate::Alt5::A, $p2 => $crate::Alt5::B, $p3 => $crate::Alt5::C, $p4 => $crate::Alt5::D, $p5 => $crate::Alt5::E)
+    };
+    ($p1:expr, $p2:expr, $p3:expr, $p4:expr, $p5:expr, $p6:expr $(,)?) => {
+        $crate::alt!($p1 => $crate::Alt6::A, $p2 => $crate::Alt6::B, $p3 => $crate::Alt6::C, $p4 => $crate::Alt6::D, $p5 => $crate::Alt6::E, $p6 => $crate::Alt6::F)
+    };
+    ($p1:expr, $p2:expr, $p3:expr, $p4:expr, $p5:expr, $p6:expr, $p7:expr $(,)?) => {
+        $crate::alt!($p1 => $crate::Alt7::A, $p2 => $crate::Alt7::B, $p3 => $crate::Alt7::C, $p4 => $crate::Alt7::D, $p5 => $crate::Alt7::E, $p6 => $crate::Alt7::F, $p7 => $crate::Alt7::G)
+    };
+    ($p1:expr, $p2:expr, $p3:expr, $p4:expr, $p5:expr, $p6:expr, $p7:expr, $p8:expr $(,)?) => {
+        $crate::alt!($p1 => $crate::Alt8::A, $p2 => $crate::Alt8::B, $p3 => $crate::Alt8::C, $p4 => $crate::Alt8::D, $p5 => $crate::Alt8::E, $p6 => $crate::Alt8::F, $p7 => $crate::Alt8::G, $p8 => $crate::Alt8::H)
+    };
+}
+
+/// `Alt2` is the value returned by `alt!` when it is given two parsers. Each arm holds the result value of the parser 
+/// at the same position in the macro arguments, so the `A` arm holds the result of the first parser, the `B` arm the result of the second, and so on.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Alt2<A, B> {
+    A(A),
+    B(B),
+}
+
+/// `Alt3` is the value returned by `alt!` when it is given three parsers. Each arm holds the result value of the parser 
+/// at the same position in the macro arguments, so the `A` arm holds the result of the first parser, the `B` arm the result of the second, and so on.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Alt3<A, B, C> {
+    A(A),
+    B(B),
+    C(C),
+}
+
+/// `Alt4` is the value returned by `alt!` when it is given four parsers. Each arm holds the result value of the parser 
+/// at the same position in the macro arguments, so the `A` arm holds the result of the first parser, the `B` arm the result of the second, and so on.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Alt4<A, B, C, D> {
+    A(A),
+    B(B),
+    C(C),
+    D(D),
+}
+
+/// `Alt5` is the value returned by `alt!` when it is given five parsers. Each arm holds the result value of the parser 
+/// at the same position in the macro arguments, so the `A` arm holds the result of the first parser, the `B` arm the result of the second, and so on.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Alt5<A, B, C, D, E> {
+    A(A),
+    B(B),
+    C(C),
+    D(D),
+    E(E),
+}
+
+/// `Alt6` is the value returned by `alt!` when it is given six parsers. Each arm holds the result value of the parser 
+/// at the same position in the macro arguments, so the `A` arm holds the result of the first parser, the `B` arm the result of the second, and so on.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Alt6<A, B, C, D, E, F> {
+    A(A),
+    B(B),
+    C(C),
+    D(D),
+    E(E),
+    F(F),
+}
+
+/// `Alt7` is the value returned by `alt!` when it is given seven parsers. Each arm holds the result value of the parser 
+/// at the same position in the macro arguments, so the `A` arm holds the result of the first parser, the `B` arm the result of the second, and so on.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Alt7<A, B, C, D, E, F, G> {
+    A(A),
+    B(B),
+    C(C),
+    D(D),
+    E(E),
+    F(F),
+    G(G),
+}
+
+/// `Alt8` is the value returned by `alt!` when it is given eight parsers. Each arm holds the result value of the parser 
+/// at the same position in the macro arguments, so the `A` arm holds the result of the first parser, the `B` arm the result of the second, and so on.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Alt8<A, B, C, D, E, F, G, H> {
+    A(A),
+    B(B),
+    C(C),
+    D(D),
+    E(E),
+    F(F),
+    G(G),
+    H(H),
+}
@@ -0,0 +1,226 @@
+use super::{ParserState, ParserSuccess, ParserFailure, ParserResult, Parser};
+
+/// `ChainFn` is the type of the combining functions returned by the operator parsers of `chain_left`, `chain_left_1`, `chain_right`,
+/// and `chain_right_1`.
+pub type ChainFn<T> = Box<dyn Fn(T, T) -> T>;
+
+/// `chain_left` applies the `parser` one or more times, separated by the `operator` parser, and combines the parsed values from left to right
+/// using the functions returned by the `operator`, so that `1 - 2 - 3` is combined as `(1 - 2) - 3`.
+/// If the `parser` fails on the first attempt, `chain_left` will return a `ParserSuccess` with the `default` value.
+///
+/// # Errors
+/// `chain_left` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `operator` fails having changed the parser state,
+/// or if the `parser` fails after the `operator` succeeded.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_minus() -> Parser<ChainFn<i32>> {
+/// #     p_char('-').then_return(Box::new(|x, y| x - y))
+/// # }
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     -4,
+///     Position::new(1, 6, 5)
+/// ));
+///
+/// let actual = chain_left(p_i32, p_minus, 0)
+///     .run(String::from("1-2-3"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+pub fn chain_left<T>(parser: fn() -> Parser<T>, operator: fn() -> Parser<ChainFn<T>>, default: T) -> Parser<T> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                match apply_first_parser(parser, state)? {
+                    Some(first) => apply_left(first, parser, operator, state),
+                    None => Ok(ParserSuccess::new(default, state.get_position())),
+                }
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `chain_left_1` applies the `parser` one or more times, separated by the `operator` parser, and combines the parsed values from left to right
+/// using the functions returned by the `operator`, so that `1 - 2 - 3` is combined as `(1 - 2) - 3`.
+///
+/// # Errors
+/// `chain_left_1` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `operator` fails having changed the parser state,
+/// or if the `parser` fails after the `operator` succeeded. Unlike `chain_left`, if the `parser` fails on the first attempt, `chain_left_1` will
+/// return a `ParserFailure`.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_minus() -> Parser<ChainFn<i32>> {
+/// #     p_char('-').then_return(Box::new(|x, y| x - y))
+/// # }
+/// #
+/// let expected = Err(ParserFailure::new_fatal_err(
+///     String::from("integral value"),
+///     None,
+///     Position::new(1, 5, 4)
+/// ));
+///
+/// let actual = chain_left_1(p_i32, p_minus)
+///     .run(String::from("1-2-"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+pub fn chain_left_1<T>(parser: fn() -> Parser<T>, operator: fn() -> Parser<ChainFn<T>>) -> Parser<T> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let first = parser().parse(state)?;
+                apply_left(first.get_result(), parser, operator, state)
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `chain_right` applies the `parser` one or more times, separated by the `operator` parser, and combines the parsed values from right to left
+/// using the functions returned by the `operator`, so that `2 ^ 3 ^ 2` is combined as `2 ^ (3 ^ 2)`.
+/// If the `parser` fails on the first attempt, `chain_right` will return a `ParserSuccess` with the `default` value.
+///
+/// # Errors
+/// `chain_right` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `operator` fails having changed the parser state,
+/// or if the `parser` fails after the `operator` succeeded.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_pow() -> Parser<ChainFn<u32>> {
+/// #     p_char('^').then_return(Box::new(|x: u32, y| x.pow(y)))
+/// # }
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     512,
+///     Position::new(1, 6, 5)
+/// ));
+///
+/// let actual = chain_right(p_u32, p_pow, 1)
+///     .run(String::from("2^3^2"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+pub fn chain_right<T>(parser: fn() -> Parser<T>, operator: fn() -> Parser<ChainFn<T>>, default: T) -> Parser<T> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                match apply_first_parser(parser, state)? {
+                    Some(first) => apply_right(first, parser, operator, state),
+                    None => Ok(ParserSuccess::new(default, state.get_position())),
+                }
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `chain_right_1` applies the `parser` one or more times, separated by the `operator` parser, and combines the parsed values from right to left
+/// using the functions returned by the `operator`, so that `2 ^ 3 ^ 2` is combined as `2 ^ (3 ^ 2)`.
+///
+/// # Errors
+/// `chain_right_1` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `operator` fails having changed the parser state,
+/// or if the `parser` fails after the `operator` succeeded. Unlike `chain_right`, if the `parser` fails on the first attempt, `chain_right_1` will
+/// return a `ParserFailure`.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_pow() -> Parser<ChainFn<u32>> {
+/// #     p_char('^').then_return(Box::new(|x: u32, y| x.pow(y)))
+/// # }
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("integral value"),
+///     None,
+///     Position::new(1, 1, 0)
+/// ));
+///
+/// let actual = chain_right_1(p_u32, p_pow)
+///     .run(String::from("^2"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+pub fn chain_right_1<T>(parser: fn() -> Parser<T>, operator: fn() -> Parser<ChainFn<T>>) -> Parser<T> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let first = parser().parse(state)?;
+                apply_right(first.get_result(), parser, operator, state)
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+fn apply_first_parser<T>(parser: fn() -> Parser<T>, state: &mut ParserState) -> Result<Option<T>, ParserFailure> {
+    match parser().parse(state) {
+        Ok(success) => Ok(Some(success.get_result())),
+        Err(failure) => {
+            if failure.is_fatal() {
+                return Err(failure)
+            }
+
+            Ok(None)
+        },
+    }
+}
+
+fn apply_operator<T>(parser: fn() -> Parser<T>, operator: fn() -> Parser<ChainFn<T>>, state: &mut ParserState) -> Result<Option<(ChainFn<T>, T)>, ParserFailure> {
+    let f = match operator().parse(state) {
+        Ok(success) => success.get_result(),
+        Err(failure) => {
+            if failure.is_fatal() {
+                return Err(failure)
+            }
+
+            return Ok(None)
+        },
+    };
+
+    match parser().parse(state) {
+        Ok(success) => Ok(Some((f, success.get_result()))),
+        Err(failure) => Err(failure.to_fatal_err()),
+    }
+}
+
+fn apply_left<T>(first: T, parser: fn() -> Parser<T>, operator: fn() -> Parser<ChainFn<T>>, state: &mut ParserState) -> ParserResult<T> {
+    let mut result = first;
+
+    while let Some((f, next)) = apply_operator(parser, operator, state)? {
+        result = f(result, next);
+    }
+
+    Ok(ParserSuccess::new(result, state.get_position()))
+}
+
+fn apply_right<T>(first: T, parser: fn() -> Parser<T>, operator: fn() -> Parser<ChainFn<T>>, state: &mut ParserState) -> ParserResult<T> {
+    let mut operands = vec![first];
+    let mut operators = Vec::new();
+
+    while let Some((f, next)) = apply_operator(parser, operator, state)? {
+        operators.push(f);
+        operands.push(next);
+    }
+
+    let mut result = operands.pop().expect("chain_right always parses at least one operand");
+
+    while let (Some(f), Some(left)) = (operators.pop(), operands.pop()) {
+        result = f(left, result);
+    }
+
+    Ok(ParserSuccess::new(result, state.get_position()))
+}
@@ -1,10 +1,11 @@
-use super::{ParserState, ParserSuccess, ParserFailure, Parser};
+use super::{ParserState, ParserSuccess, ParserFailure, Parser, no_progress_failure};
 
 /// `many` applies the parser `many_parser` repeatedly until it fails, returning the parsed values in a Vector as a `ParserSuccess`.
 /// If the `many_parser` fails on the first attempt then `many` will return a `ParserSuccess` with an empty Vector.
 /// 
 /// # Errors
-/// `many` will return a `ParserFailure` if the `many_parser` fails with a `FatalError`.
+/// `many` will return a `ParserFailure` if the `many_parser` fails with a `FatalError`. If the `many_parser` succeeds without consuming any input,
+/// `many` would never stop applying it, so a `FatalError` naming `many` is returned instead. The same check is made by every repetition combinator.
 /// 
 /// # Examples
 /// 
@@ -29,7 +30,7 @@ pub fn many<T>(many_parser: fn() -> Parser<T>) -> Parser<Vec<T>> {
     let parser_fn =
         Box::new(
             move |state: &mut ParserState| {
-                let results: Vec<T> = apply_parser(many_parser, state)?;
+                let results: Vec<T> = apply_parser("many", many_parser, state)?;
                 Ok(ParserSuccess::new(results, state.get_position()))
             }
         );
@@ -69,7 +70,7 @@ pub fn many_1<T>(many_parser: fn() -> Parser<T>) -> Parser<Vec<T>> {
             move |state: &mut ParserState| {
                 match many_parser().parse(state) {
                     Ok(success) => {
-                        let mut results = apply_parser(many_parser, state)?;
+                        let mut results = apply_parser("many_1", many_parser, state)?;
                         results.insert(0, success.get_result());
                         Ok(ParserSuccess::new(results, state.get_position()))
                     },
@@ -110,7 +111,7 @@ pub fn skip_many<T>(many_parser: fn() -> Parser<T>) -> Parser<()> {
     let parser_fn =
         Box::new(
             move |state: &mut ParserState| {
-                let _ = apply_parser(many_parser, state)?;
+                let _ = apply_parser("skip_many", many_parser, state)?;
                 Ok(ParserSuccess::new((), state.get_position()))
             }
         );
@@ -150,7 +151,7 @@ pub fn skip_many_1<T>(many_parser: fn() -> Parser<T>) -> Parser<()> {
             move |state: &mut ParserState| {
                 match many_parser().parse(state) {
                     Ok(_) => {
-                        let _ = apply_parser(many_parser, state)?;
+                        let _ = apply_parser("skip_many_1", many_parser, state)?;
                         Ok(ParserSuccess::new((), state.get_position()))
                     },
                     Err(failure) => Err(failure),
@@ -161,14 +162,227 @@ pub fn skip_many_1<T>(many_parser: fn() -> Parser<T>) -> Parser<()> {
     Parser::new(parser_fn)
 }
 
-fn apply_parser<T>(p: fn() -> Parser<T>, state: &mut ParserState) -> Result<Vec<T>, ParserFailure> {
-    let mut results: Vec<T> = Vec::new();
+/// `count` applies the parser `many_parser` exactly `n` times, returning the parsed values in a Vector as a `ParserSuccess`.
+/// 
+/// # Errors
+/// `count` will return a `ParserFailure` if the `many_parser` fails with a `FatalError` or if the `many_parser` succeeds fewer than `n` times. The
+/// failure states how many values were expected and how many were found. It is a `FatalError` if at least one value was parsed, otherwise it is an `Error`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     vec!['a', 'b', 'c'], 
+///     Position::new(1, 4, 3)
+/// ));
+/// 
+/// let actual = count(3, letter)
+///     .run(String::from("abcd"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn count<T>(n: usize, many_parser: fn() -> Parser<T>) -> Parser<Vec<T>> {
+    range("count", n, n, many_parser)
+}
+
+/// `skip_count` applies the parser `many_parser` exactly `n` times, returning a `ParserSuccess` of `()`.
+/// 
+/// # Errors
+/// `skip_count` will return a `ParserFailure` if the `many_parser` fails with a `FatalError` or if the `many_parser` succeeds fewer than `n` times. The
+/// failure states how many values were expected and how many were found. It is a `FatalError` if at least one value was parsed, otherwise it is an `Error`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_fatal_err(
+///     String::from("3 values satisfying parser"), 
+///     Some(String::from("2 values")),
+///     Position::new(1, 3, 2)
+/// ));
+/// 
+/// let actual = skip_count(3, letter)
+///     .run(String::from("ab1"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn skip_count<T>(n: usize, many_parser: fn() -> Parser<T>) -> Parser<()> {
+    skip_range("skip_count", n, n, many_parser)
+}
+
+/// `many_range` applies the parser `many_parser` repeatedly until it fails or until it has succeeded `max` times, returning the parsed values in a Vector
+/// as a `ParserSuccess`. The `many_parser` must succeed at least `min` times.
+/// 
+/// # Errors
+/// `many_range` will return a `ParserFailure` if the `many_parser` fails with a `FatalError` or if the `many_parser` succeeds fewer than `min` times. The
+/// failure states how many values were expected and how many were found. It is a `FatalError` if at least one value was parsed, otherwise it is an `Error`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     vec!['1', '2', '3', '4'], 
+///     Position::new(1, 5, 4)
+/// ));
+/// 
+/// let actual = many_range(2, 4, digit)
+///     .run(String::from("123456"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_range<T>(min: usize, max: usize, many_parser: fn() -> Parser<T>) -> Parser<Vec<T>> {
+    range("many_range", min, max, many_parser)
+}
+
+/// `skip_many_range` applies the parser `many_parser` repeatedly until it fails or until it has succeeded `max` times, returning a `ParserSuccess` of `()`.
+/// The `many_parser` must succeed at least `min` times.
+/// 
+/// # Errors
+/// `skip_many_range` will return a `ParserFailure` if the `many_parser` fails with a `FatalError` or if the `many_parser` succeeds fewer than `min` times. The
+/// failure states how many values were expected and how many were found. It is a `FatalError` if at least one value was parsed, otherwise it is an `Error`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("at least 2 values satisfying parser"), 
+///     Some(String::from("0 values")),
+///     Position::new(1, 1, 0)
+/// ));
+/// 
+/// let actual = skip_many_range(2, 4, digit)
+///     .run(String::from("abc"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn skip_many_range<T>(min: usize, max: usize, many_parser: fn() -> Parser<T>) -> Parser<()> {
+    skip_range("skip_many_range", min, max, many_parser)
+}
+
+/// `many_fold` applies the parser `many_parser` repeatedly until it fails, combining each parsed value with an accumulator using the function `f`,
+/// starting with `init`. The final value of the accumulator is returned as a `ParserSuccess`, without collecting the parsed values into a Vector.
+/// If the `many_parser` fails on the first attempt then `many_fold` will return a `ParserSuccess` of `init`.
+/// 
+/// # Errors
+/// `many_fold` will return a `ParserFailure` if the `many_parser` fails with a `FatalError`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_number() -> Parser<u32> {
+/// #     p_u32().take_prev(ws())
+/// # }
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     6, 
+///     Position::new(1, 6, 5)
+/// ));
+/// 
+/// let actual = many_fold(p_number, 0, Box::new(|sum, n| sum + n))
+///     .run(String::from("1 2 3"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_fold<T, A>(many_parser: fn() -> Parser<T>, init: A, f: Box<dyn Fn(A, T) -> A>) -> Parser<A>
+where A: 'static
+{
+    fold("many_fold", many_parser, init, f)
+}
+
+/// `many_collect` applies the parser `many_parser` repeatedly until it fails, extending a collection of type `C` with each parsed value as it is parsed.
+/// The collection is returned as a `ParserSuccess`. Any type implementing `Default` and `Extend`, such as a `String`, a `HashSet` or a `HashMap`,
+/// can be used as the collection. If the `many_parser` fails on the first attempt then `many_collect` will return a `ParserSuccess` with an empty collection.
+/// 
+/// # Errors
+/// `many_collect` will return a `ParserFailure` if the `many_parser` fails with a `FatalError`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// # use std::collections::HashSet;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     HashSet::from(['a', 'b']), 
+///     Position::new(1, 5, 4)
+/// ));
+/// 
+/// let actual = many_collect::<char, HashSet<char>>(letter)
+///     .run(String::from("abba1"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_collect<T, C>(many_parser: fn() -> Parser<T>) -> Parser<C>
+where C: Default + Extend<T> + 'static
+{
+    fold("many_collect", many_parser, C::default(), Box::new(|mut results: C, result| { results.extend(std::iter::once(result)); results }))
+}
+
+fn range<T>(name: &'static str, min: usize, max: usize, many_parser: fn() -> Parser<T>) -> Parser<Vec<T>> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let results = apply_parser_range(name, many_parser, min, max, state)?;
+                Ok(ParserSuccess::new(results, state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+fn skip_range<T>(name: &'static str, min: usize, max: usize, many_parser: fn() -> Parser<T>) -> Parser<()> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let _ = apply_parser_range(name, many_parser, min, max, state)?;
+                Ok(ParserSuccess::new((), state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+fn fold<T, A>(name: &'static str, many_parser: fn() -> Parser<T>, init: A, f: Box<dyn Fn(A, T) -> A>) -> Parser<A>
+where A: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let result = fold_parser(name, many_parser, init, &f, state)?;
+                Ok(ParserSuccess::new(result, state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+fn apply_parser<T>(name: &str, p: fn() -> Parser<T>, state: &mut ParserState) -> Result<Vec<T>, ParserFailure> {
+    fold_parser(name, p, Vec::new(), &|mut results: Vec<T>, result| { results.push(result); results }, state)
+}
+
+fn fold_parser<T, A>(name: &str, p: fn() -> Parser<T>, init: A, f: &dyn Fn(A, T) -> A, state: &mut ParserState) -> Result<A, ParserFailure> {
+    let mut acc = init;
     let mut parser_succeeds = true;
 
     while parser_succeeds {
+        let start = state.get_index();
+
         match p().parse(state) {
             Ok(success) => {
-                results.push(success.get_result());
+                if state.get_index() == start {
+                    return Err(no_progress_failure(name, state))
+                }
+
+                acc = f(acc, success.get_result());
             },
             Err(failure) => {
                 if failure.is_fatal() {
@@ -179,5 +393,47 @@ fn apply_parser<T>(p: fn() -> Parser<T>, state: &mut ParserState) -> Result<Vec<
         }
     }
 
+    Ok(acc)
+}
+
+fn apply_parser_range<T>(name: &str, p: fn() -> Parser<T>, min: usize, max: usize, state: &mut ParserState) -> Result<Vec<T>, ParserFailure> {
+    let mut results: Vec<T> = Vec::new();
+
+    while results.len() < max {
+        let start = state.get_index();
+
+        match p().parse(state) {
+            Ok(success) => {
+                if state.get_index() == start {
+                    return Err(no_progress_failure(name, state))
+                }
+
+                results.push(success.get_result());
+            },
+            Err(failure) => {
+                if failure.is_fatal() {
+                    return Err(failure)
+                }
+                break;
+            },
+        }
+    }
+
+    if results.len() < min {
+        let expected = if min == max {
+            format!("{} values satisfying parser", min)
+        } else {
+            format!("at least {} values satisfying parser", min)
+        };
+
+        let failure = ParserFailure::new_err(expected, Some(format!("{} values", results.len())), state.get_position());
+
+        return if results.is_empty() {
+            Err(failure)
+        } else {
+            Err(failure.to_fatal_err())
+        }
+    }
+
     Ok(results)
 }
\ No newline at end of file
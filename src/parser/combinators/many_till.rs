@@ -1,4 +1,4 @@
-use super::{ParserState, ParserSuccess, ParserFailure, Parser};
+use super::{ParserState, ParserSuccess, ParserFailure, Parser, no_progress_failure};
 
 /// `many_till` takes two parsers and applies the first parser (`many_parser`) repeatedly until the second parser (`end_parser`) succeeds. Once the 
 /// `end_parser` succeeds, then all values parsed by the `many_parser` are returned in a Vector as a `ParserSuccess`.
@@ -6,6 +6,7 @@ use super::{ParserState, ParserSuccess, ParserFailure, Parser};
 /// 
 /// # Errors
 /// `many_till` will return a `ParserFailure` if the `many_parser` fails with a `FatalError` or if the `many_parser` fails and is followed by a failing `end_parser`.
+/// A `FatalError` is also returned if the `many_parser` succeeds without consuming any input and the `end_parser` fails.
 /// 
 /// # Examples
 /// 
@@ -31,7 +32,7 @@ pub fn many_till<T, U>(many_parser: fn() -> Parser<T>, end_parser: fn() -> Parse
     let parser_fn =
         Box::new(
             move |state: &mut ParserState| {
-                let results = apply_parsers(many_parser, end_parser, state)?;
+                let (results, _) = apply_parsers("many_till", many_parser, end_parser, state)?;
                 Ok(ParserSuccess::new(results, state.get_position()))
             }
         );
@@ -72,7 +73,7 @@ pub fn many_1_till<T, U>(many_parser: fn() -> Parser<T>, end_parser: fn() -> Par
     let parser_fn =
         Box::new(
             move |state: &mut ParserState| {
-                let results = apply_parsers_1(many_parser, end_parser, state)?;
+                let (results, _) = apply_parsers_1("many_1_till", many_parser, end_parser, state)?;
                 Ok(ParserSuccess::new(results, state.get_position()))
             }
         );
@@ -111,7 +112,7 @@ pub fn skip_many_till<T, U>(many_parser: fn() -> Parser<T>, end_parser: fn() ->
     let parser_fn =
         Box::new(
             move |state: &mut ParserState| {
-                let _ = apply_parsers(many_parser, end_parser, state)?;
+                let _ = apply_parsers("skip_many_till", many_parser, end_parser, state)?;
                 Ok(ParserSuccess::new((), state.get_position()))
             }
         );
@@ -152,7 +153,7 @@ pub fn skip_many_1_till<T, U>(many_parser: fn() -> Parser<T>, end_parser: fn() -
     let parser_fn =
         Box::new(
             move |state: &mut ParserState| {
-                let _ = apply_parsers_1(many_parser, end_parser, state)?;
+                let _ = apply_parsers_1("skip_many_1_till", many_parser, end_parser, state)?;
                 Ok(ParserSuccess::new((), state.get_position()))
             }
         );
@@ -160,41 +161,204 @@ pub fn skip_many_1_till<T, U>(many_parser: fn() -> Parser<T>, end_parser: fn() -
     Parser::new(parser_fn)
 }
 
-fn apply_parsers<T, U>(many_parser: fn() -> Parser<T>, end_parser: fn() -> Parser<U>, state: &mut ParserState) -> Result<Vec<T>, ParserFailure> {
+/// `many_till_with_end` takes two parsers and applies the first parser (`many_parser`) repeatedly until the second parser (`end_parser`) succeeds. Once the 
+/// `end_parser` succeeds, then all values parsed by the `many_parser` are returned in a Vector, together with the value parsed by the `end_parser`, as a `ParserSuccess`.
+/// If the `many_parser` fails on the first attempt and the `end_parser` succeeds, then `many_till_with_end` will return a `ParserSuccess` with an empty Vector.
+/// 
+/// # Errors
+/// `many_till_with_end` will return a `ParserFailure` if the `many_parser` fails with a `FatalError` or if the `many_parser` fails and is followed by a failing `end_parser`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_true() -> Parser<bool> {
+/// #     p_string(String::from("true"))
+/// #         .then_return(true)
+/// # }
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     (vec![true, true], 123),
+///     Position::new(1, 12, 11)
+/// ));
+/// 
+/// let actual = many_till_with_end(p_true, p_u32)
+///     .run(String::from("truetrue123"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_till_with_end<T, U>(many_parser: fn() -> Parser<T>, end_parser: fn() -> Parser<U>) -> Parser<(Vec<T>, U)> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let results = apply_parsers("many_till_with_end", many_parser, end_parser, state)?;
+                Ok(ParserSuccess::new(results, state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `many_1_till_with_end` takes two parsers and applies the first parser (`many_parser`) repeatedly until the second parser (`end_parser`) succeeds. Once the 
+/// `end_parser` succeeds, then all values parsed by the `many_parser` are returned in a Vector, together with the value parsed by the `end_parser`, as a `ParserSuccess`.
+/// 
+/// # Errors
+/// `many_1_till_with_end` will return a `ParserFailure` if the `many_parser` fails with a `FatalError` or if the `many_parser` fails and is followed by a failing `end_parser`.
+/// Unlike `many_till_with_end`, if the `many_parser` fails on the first attempt and the `end_parser` succeeds, `many_1_till_with_end` will return a `ParserFailure`. The
+/// `many_parser` must succeed at least once for `many_1_till_with_end` to return a `ParserSuccess`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_true() -> Parser<bool> {
+/// #     p_string(String::from("true"))
+/// #         .then_return(true)
+/// # }
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("true"), 
+///     Some(String::from("1234")), 
+///     Position::new(1, 1, 0)
+/// ));
+/// 
+/// let actual = many_1_till_with_end(p_true, p_u32)
+///     .run(String::from("1234"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_1_till_with_end<T, U>(many_parser: fn() -> Parser<T>, end_parser: fn() -> Parser<U>) -> Parser<(Vec<T>, U)> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let results = apply_parsers_1("many_1_till_with_end", many_parser, end_parser, state)?;
+                Ok(ParserSuccess::new(results, state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `many_chars_till` takes a function (`f`) of type `(char) -> bool` and a parser (`end_parser`). At each position in the input string it first applies
+/// the `end_parser`, and if the `end_parser` fails, it parses the next character if that character returns true when applied to the function `f`. Once the
+/// `end_parser` succeeds, the input consumed before the `end_parser` was applied is returned as a single string in a `ParserSuccess`. Because the `end_parser` is tried first, `f` may also
+/// accept the characters that begin the `end_parser`.
+/// 
+/// # Errors
+/// `many_chars_till` will return a `ParserFailure` if the `end_parser` fails with a `FatalError` or if the `end_parser` fails and the next character does not
+/// return true when applied to the function `f`, including at the end of the input. In that case the failure of the `end_parser` is returned, as a `FatalError`
+/// if any characters have been parsed.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_close_comment() -> Parser<String> {
+/// #     p_string(String::from("-->"))
+/// # }
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from(" a -> b "),
+///     Position::new(1, 12, 11)
+/// ));
+/// 
+/// let actual = many_chars_till(Box::new(|_| true), p_close_comment)
+///     .run(String::from(" a -> b -->"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn many_chars_till<U>(f: Box<dyn Fn (char) -> bool>, end_parser: fn() -> Parser<U>) -> Parser<String> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let start = state.get_index();
+
+                loop {
+                    let end = state.get_index();
+
+                    let failure = match end_parser().parse(state) {
+                        Ok(_) => {
+                            let result = state.get_slice_between(start, end);
+                            return Ok(ParserSuccess::new(result, state.get_position()))
+                        },
+                        Err(failure) => failure,
+                    };
+
+                    if failure.is_fatal() {
+                        return Err(failure)
+                    }
+
+                    match state.get_remaining_input().chars().next() {
+                        Some(c) if f(c) => {
+                            state.move_state_forward(c.len_utf8())?;
+                        },
+                        _ => {
+                            return if end == start {
+                                Err(failure)
+                            } else {
+                                Err(failure.to_fatal_err())
+                            }
+                        },
+                    }
+                }
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+fn apply_parsers<T, U>(name: &str, many_parser: fn() -> Parser<T>, end_parser: fn() -> Parser<U>, state: &mut ParserState) -> Result<(Vec<T>, U), ParserFailure> {
     let mut results: Vec<T> = Vec::new();
-    let mut end_parser_succeeds = false;
 
-    while !end_parser_succeeds {
+    loop {
+        let start = state.get_index();
+
         match many_parser().parse(state) {
             Ok(success) => {
-                end_parser_succeeds = apply_end_parser(end_parser, state)?;
-                results.push(success.get_result())
+                results.push(success.get_result());
+
+                if let Some(end) = apply_end_parser(end_parser, state)? {
+                    return Ok((results, end))
+                }
+
+                if state.get_index() == start {
+                    return Err(no_progress_failure(name, state))
+                }
             },
             Err(failure) => {
-                return if results.len() == 0 && !failure.is_fatal() {
-                    end_parser().parse(state).and(Ok(results))
+                return if results.is_empty() && !failure.is_fatal() {
+                    end_parser().parse(state).map(|end| (results, end.get_result()))
                 } else {
                     Err(failure.to_fatal_err())
                 }
             },
         }
     }
-
-    Ok(results)
 }
 
-fn apply_parsers_1<T, U>(many_parser: fn() -> Parser<T>, end_parser: fn() -> Parser<U>, state: &mut ParserState) -> Result<Vec<T>, ParserFailure> {
+fn apply_parsers_1<T, U>(name: &str, many_parser: fn() -> Parser<T>, end_parser: fn() -> Parser<U>, state: &mut ParserState) -> Result<(Vec<T>, U), ParserFailure> {
     let mut results: Vec<T> = Vec::new();
-    let mut end_parser_succeeds = false;
 
-    while !end_parser_succeeds {
+    loop {
+        let start = state.get_index();
+
         match many_parser().parse(state) {
             Ok(success) => {
-                end_parser_succeeds = apply_end_parser(end_parser, state)?;
-                results.push(success.get_result())
+                results.push(success.get_result());
+
+                if let Some(end) = apply_end_parser(end_parser, state)? {
+                    return Ok((results, end))
+                }
+
+                if state.get_index() == start {
+                    return Err(no_progress_failure(name, state))
+                }
             },
             Err(failure) => {
-                return if results.len() == 0 {
+                return if results.is_empty() {
                     Err(failure)
                 } else {
                     Err(failure.to_fatal_err())
@@ -202,21 +366,19 @@ fn apply_parsers_1<T, U>(many_parser: fn() -> Parser<T>, end_parser: fn() -> Par
             },
         }
     }
-
-    Ok(results)
 }
 
-fn apply_end_parser<T>(end_parser: fn() -> Parser<T>, state: &mut ParserState) -> Result<bool, ParserFailure> {
+fn apply_end_parser<T>(end_parser: fn() -> Parser<T>, state: &mut ParserState) -> Result<Option<T>, ParserFailure> {
     match end_parser().parse(state) {
-        Ok(_) => {
-            Ok(true)
+        Ok(success) => {
+            Ok(Some(success.get_result()))
         },
         Err(failure) => {
             if failure.is_fatal() {
                 return Err(failure)
             }
 
-            Ok(false)
+            Ok(None)
         }
     }
-}
\ No newline at end of file
+}
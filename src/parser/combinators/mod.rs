@@ -2,9 +2,24 @@ pub mod many;
 pub mod sep_by;
 pub mod many_till;
 pub mod pipe;
+pub mod operator_precedence;
+pub mod chain;
+pub mod permutation;
+pub mod alt;
+pub mod rule;
 
 use super::{ParserState, ParserSuccess, ParserFailure, ParserResult, Parser};
 
+/// `no_progress_failure` is returned by the repetition combinators when an iteration succeeds without consuming any input, because repeating
+/// that iteration would never end. The failure names the combinator `name` and is reported at the position where the iteration was applied.
+pub(in crate::parser) fn no_progress_failure(name: &str, state: &ParserState) -> ParserFailure {
+    ParserFailure::new_fatal_err(
+        format!("parser applied by {} to consume input", name),
+        Some("parser succeeded without consuming input".to_string()),
+        state.get_position()
+    )
+}
+
 /// `choice` takes a Vector of parsers and applies each one in sequence until one of the parsers returns a `ParserSuccess`. Each parser in the 
 /// Vector must return a `ParserSuccess` with the same value type.
 /// 
@@ -95,7 +110,8 @@ pub fn choice_l<T>(parsers: Vec<Parser<T>>, label: String) -> Parser<T> {
 /// # Errors
 /// `attempt` will only every return a `ParserFailure` with an `Error` severity. If the `parser` fails having changed the parser state, because it is
 /// able to revert the current `Position` as well as the history of the `ParserState` exactly as it was before the `parser` was applied, the parser state
-/// is no longer considered changed and therefore `attempt` can safely return an `Error` severity.
+/// is no longer considered changed and therefore `attempt` can safely return an `Error` severity. The only exception is when the `parser` fails after
+/// a `cut`, in which case the parser state is not reverted and the failure is returned as a `FatalError` (see `cut`).
 /// 
 /// # Examples
 /// 
@@ -122,14 +138,21 @@ where T: 'static
     let parser_fn = 
         Box::new(
             move |state: &mut ParserState| {
-                state.mark();
+                let start = state.get_index();
+                let cuts = state.get_cuts();
+
                 match parser.parse(state) {
                     Ok(success) => {
-                        state.remove_mark();
+                        state.reset_cuts(cuts);
                         Ok(success)
                     },
                     Err(failure) => {
-                        state.revert();
+                        if state.get_cuts() > cuts {
+                            state.reset_cuts(cuts);
+                            return Err(failure.to_fatal_err())
+                        }
+
+                        state.revert_to(start);
                         Err(failure.to_err())
                     },
                 }
@@ -137,4 +160,227 @@ where T: 'static
         );
 
     Parser::new(parser_fn)
-}
\ No newline at end of file
+}
+
+/// `cut` always succeeds without consuming any input, and marks a commit point in a sequence of parsers. Once a `cut` has been applied, 
+/// any failure of the parsers that follow it is treated as a `FatalError`, even inside `attempt`, `and_try`, `try_take_prev`, `try_take_next` 
+/// and `try_bind`, which will no longer revert the parser state. As a result `or` and `choice` will report the failure instead of trying 
+/// the remaining alternatives.
+/// 
+/// A `cut` only commits the nearest enclosing `attempt` (or `try_*` combinator). Once that parser has finished, whether it succeeded or failed, the cut
+/// is discarded and does not affect any parsers applied after it, so an enclosing `attempt` is still able to revert the parser state.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_fatal_err(
+///     String::from("integral value"),
+///     None,
+///     Position::new(1, 5, 4)
+/// ));
+/// 
+/// let actual = choice(vec![
+///     attempt(p_string(String::from("let")).take_prev(cut()).take_prev(ws()).take_next(p_u32())),
+///     p_string(String::from("letter")).then_return(0),
+/// ]).run(String::from("let x"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn cut() -> Parser<()> {
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                state.cut();
+                Ok(ParserSuccess::new((), state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `lookahead` applies the `parser` and then restores the parser state to the position before the `parser` was applied. If the `parser` succeeds,
+/// its result is returned as a `ParserSuccess` without consuming any input.
+/// 
+/// # Errors
+/// `lookahead` will only ever return a `ParserFailure` with an `Error` severity. If the `parser` fails, the parser state is restored and the failure
+/// of the `parser` is returned as an `Error`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     (String::from("abc"), 123),
+///     Position::new(1, 1, 0)
+/// ));
+/// 
+/// let actual = lookahead(p_string(String::from("abc")).and(p_u32()))
+///     .run(String::from("abc123"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn lookahead<T>(parser: Parser<T>) -> Parser<T>
+where T: 'static
+{
+    let parser_fn = 
+        Box::new(
+            move |state: &mut ParserState| {
+                let start = state.get_index();
+                let result = parser.parse(state);
+                state.revert_to(start);
+
+                match result {
+                    Ok(success) => Ok(success.with_position(state.get_position())),
+                    Err(failure) => Err(failure.to_err()),
+                }
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `followed_by` succeeds with `()` if the `parser` succeeds at the current position, without consuming any input.
+/// 
+/// # Errors
+/// `followed_by` will return a `ParserFailure` with an `Error` severity if the `parser` fails. The parser state is always restored, so `followed_by`
+/// can be combined with `or` and `choice`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("following parser to succeed"),
+///     None,
+///     Position::new(1, 1, 0)
+/// ));
+/// 
+/// let actual = followed_by(p_string(String::from("abc")).and(p_u32()))
+///     .run(String::from("abcdef"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn followed_by<T>(parser: Parser<T>) -> Parser<()>
+where T: 'static
+{
+    followed_by_l(parser, "following parser to succeed".to_string())
+}
+
+/// `followed_by_l` works exactly like `followed_by` with one difference, it allows for a custom error message to be attached to the parser.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     (),
+///     Position::new(1, 1, 0)
+/// ));
+/// 
+/// let actual = followed_by_l(p_u32(), String::from("number"))
+///     .run(String::from("123"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn followed_by_l<T>(parser: Parser<T>, label: String) -> Parser<()>
+where T: 'static
+{
+    let parser_fn = 
+        Box::new(
+            move |state: &mut ParserState| {
+                let start = state.get_index();
+                let result = parser.parse(state);
+                state.revert_to(start);
+
+                match result {
+                    Ok(_) => Ok(ParserSuccess::new((), state.get_position())),
+                    Err(_) => {
+                        Err(ParserFailure::new_err(
+                            label,
+                            None,
+                            state.get_position()
+                        ))
+                    },
+                }
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `not_followed_by` succeeds with `()` if the `parser` fails at the current position, without consuming any input.
+/// 
+/// # Errors
+/// `not_followed_by` will return a `ParserFailure` with an `Error` severity if the `parser` succeeds. The parser state is always restored, so 
+/// `not_followed_by` can be combined with `or` and `choice`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("if"),
+///     Position::new(1, 3, 2)
+/// ));
+/// 
+/// let actual = p_string(String::from("if"))
+///     .take_prev(not_followed_by(letter()))
+///     .run(String::from("if x"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn not_followed_by<T>(parser: Parser<T>) -> Parser<()>
+where T: 'static
+{
+    not_followed_by_l(parser, "following parser to fail".to_string())
+}
+
+/// `not_followed_by_l` works exactly like `not_followed_by` with one difference, it allows for a custom error message to be attached to the parser.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("anything but a comment"),
+///     None,
+///     Position::new(1, 1, 0)
+/// ));
+/// 
+/// let actual = not_followed_by_l(p_string(String::from("//")), String::from("anything but a comment"))
+///     .run(String::from("// comment"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn not_followed_by_l<T>(parser: Parser<T>, label: String) -> Parser<()>
+where T: 'static
+{
+    let parser_fn = 
+        Box::new(
+            move |state: &mut ParserState| {
+                let start = state.get_index();
+                let result = parser.parse(state);
+                state.revert_to(start);
+
+                match result {
+                    Ok(_) => {
+                        Err(ParserFailure::new_err(
+                            label,
+                            None,
+                            state.get_position()
+                        ))
+                    },
+                    Err(_) => Ok(ParserSuccess::new((), state.get_position())),
+                }
+            }
+        );
+
+    Parser::new(parser_fn)
+}
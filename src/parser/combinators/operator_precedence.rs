@@ -0,0 +1,340 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::{ParserState, ParserSuccess, ParserFailure, ParserResult, Parser};
+use super::super::trie::Trie;
+
+/// `Associativity` describes how a sequence of operators with the same precedence is grouped. `Left` groups `a - b - c` as `(a - b) - c`,
+/// `Right` groups `a ^ b ^ c` as `a ^ (b ^ c)`, and `None` does not allow two operators of the same precedence next to each other.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Associativity {
+    Left,
+    Right,
+    None,
+}
+
+struct PrefixOperator<T> {
+    precedence: u32,
+    mapping: Box<dyn Fn(T) -> T>,
+}
+
+enum AfterTermOperator<T> {
+    Infix {
+        precedence: u32,
+        associativity: Associativity,
+        mapping: Box<dyn Fn(T, T) -> T>,
+    },
+    Postfix {
+        precedence: u32,
+        mapping: Box<dyn Fn(T) -> T>,
+    },
+    Ternary {
+        right: String,
+        precedence: u32,
+        associativity: Associativity,
+        mapping: Box<dyn Fn(T, T, T) -> T>,
+    },
+}
+
+impl<T> AfterTermOperator<T> {
+    fn precedence(&self) -> u32 {
+        match self {
+            AfterTermOperator::Infix { precedence, .. } => *precedence,
+            AfterTermOperator::Postfix { precedence, .. } => *precedence,
+            AfterTermOperator::Ternary { precedence, .. } => *precedence,
+        }
+    }
+
+    fn associativity(&self) -> Option<Associativity> {
+        match self {
+            AfterTermOperator::Infix { associativity, .. } => Some(*associativity),
+            AfterTermOperator::Postfix { .. } => None,
+            AfterTermOperator::Ternary { associativity, .. } => Some(*associativity),
+        }
+    }
+}
+
+/// `Term` is the parser used for the operands of an `OperatorPrecedenceParser`. A `Recursive` term is given the `OperatorPrecedenceParser` it belongs to,
+/// so that terms containing nested expressions can reuse the same operator table.
+enum Term<T>
+where T: 'static
+{
+    Simple(fn() -> Parser<T>),
+    Recursive(fn(&OperatorPrecedenceParser<T>) -> Parser<T>),
+}
+
+struct OperatorTable<T>
+where T: 'static
+{
+    term: Term<T>,
+    after_operator: fn() -> Parser<()>,
+    prefix: Vec<(String, PrefixOperator<T>)>,
+    prefix_trie: Trie,
+    after_term: Vec<(String, AfterTermOperator<T>)>,
+    after_term_trie: Trie,
+}
+
+/// `OperatorPrecedenceParser` builds a parser for expression grammars out of a term parser and a table of prefix, infix, postfix, and ternary
+/// operators, each with a precedence and a mapping function used to combine the parsed operands. Operators with a higher precedence bind
+/// more tightly. The `after_operator` parser is applied after every operator string and is typically used to skip whitespace.
+///
+/// Cloning an `OperatorPrecedenceParser` shares the operator table, and operators added to the table are also used by expression parsers
+/// that were created before the operators were added. Operators must not be added while an expression is being parsed. A term that contains
+/// nested expressions, such as an expression in parentheses, should be created with `new_recursive`, so that the nested expressions are parsed
+/// with the same operator table instead of building a new one at every level of nesting.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// fn p_term(opp: &OperatorPrecedenceParser<i32>) -> Parser<i32> {
+///     let p_parens = opp.expression_parser()
+///         .between(p_char('(').take_prev(ws()), p_char(')'));
+///
+///     p_i32().or(p_parens).take_prev(ws())
+/// }
+///
+/// let opp = OperatorPrecedenceParser::new_recursive(p_term, ws);
+/// opp.add_infix_operator("+", 1, Associativity::Left, Box::new(|x, y| x + y));
+/// opp.add_infix_operator("*", 2, Associativity::Left, Box::new(|x, y| x * y));
+/// opp.add_prefix_operator("-", 3, Box::new(|x: i32| -x));
+///
+/// let expected = Ok(ParserSuccess::new(
+///     -5,
+///     Position::new(1, 11, 10)
+/// ));
+///
+/// let actual = opp.expression_parser()
+///     .run(String::from("1 + 2 * -3"));
+///
+/// assert_eq!(actual, expected);
+///
+/// opp.add_infix_operator("-", 1, Associativity::Left, Box::new(|x, y| x - y));
+///
+/// let expected = Ok(ParserSuccess::new(
+///     7,
+///     Position::new(1, 13, 12)
+/// ));
+///
+/// let actual = opp.expression_parser()
+///     .run(String::from("(1 - 2) * -7"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+pub struct OperatorPrecedenceParser<T>
+where T: 'static
+{
+    table: Rc<RefCell<OperatorTable<T>>>,
+}
+
+impl<T> Clone for OperatorPrecedenceParser<T> {
+    fn clone(&self) -> OperatorPrecedenceParser<T> {
+        OperatorPrecedenceParser { table: Rc::clone(&self.table) }
+    }
+}
+
+impl<T> OperatorPrecedenceParser<T> {
+    /// `new` creates an `OperatorPrecedenceParser` with an empty operator table. The `term` parser is used for the operands of the operators
+    /// and the `after_operator` parser is applied after each operator string.
+    pub fn new(term: fn() -> Parser<T>, after_operator: fn() -> Parser<()>) -> OperatorPrecedenceParser<T> {
+        OperatorPrecedenceParser::with_term(Term::Simple(term), after_operator)
+    }
+
+    /// `new_recursive` works like `new`, except that the `term` function is given the `OperatorPrecedenceParser` each time a term is parsed.
+    /// The term can then use `expression_parser` to parse nested expressions with the same operator table.
+    pub fn new_recursive(term: fn(&OperatorPrecedenceParser<T>) -> Parser<T>, after_operator: fn() -> Parser<()>) -> OperatorPrecedenceParser<T> {
+        OperatorPrecedenceParser::with_term(Term::Recursive(term), after_operator)
+    }
+
+    fn with_term(term: Term<T>, after_operator: fn() -> Parser<()>) -> OperatorPrecedenceParser<T> {
+        let table = OperatorTable {
+            term,
+            after_operator,
+            prefix: Vec::new(),
+            prefix_trie: Trie::new(&[]),
+            after_term: Vec::new(),
+            after_term_trie: Trie::new(&[]),
+        };
+
+        OperatorPrecedenceParser { table: Rc::new(RefCell::new(table)) }
+    }
+
+    /// `add_infix_operator` adds a binary operator that is placed between its two operands. Any infix, postfix, or ternary operator
+    /// already registered with the same `operator` string is replaced.
+    pub fn add_infix_operator(&self, operator: &str, precedence: u32, associativity: Associativity, mapping: Box<dyn Fn(T, T) -> T>) {
+        self.table.borrow_mut().add_after_term(operator, AfterTermOperator::Infix { precedence, associativity, mapping });
+    }
+
+    /// `add_postfix_operator` adds a unary operator that follows its operand. Any infix, postfix, or ternary operator already registered
+    /// with the same `operator` string is replaced.
+    pub fn add_postfix_operator(&self, operator: &str, precedence: u32, mapping: Box<dyn Fn(T) -> T>) {
+        self.table.borrow_mut().add_after_term(operator, AfterTermOperator::Postfix { precedence, mapping });
+    }
+
+    /// `add_ternary_operator` adds an operator made of two strings, `left` and `right`, that separate three operands, like `a ? b : c`.
+    /// The middle operand may be any expression. Any infix, postfix, or ternary operator already registered with the same `left` string
+    /// is replaced.
+    pub fn add_ternary_operator(&self, left: &str, right: &str, precedence: u32, associativity: Associativity, mapping: Box<dyn Fn(T, T, T) -> T>) {
+        let right = right.to_string();
+        self.table.borrow_mut().add_after_term(left, AfterTermOperator::Ternary { right, precedence, associativity, mapping });
+    }
+
+    /// `add_prefix_operator` adds a unary operator that precedes its operand. Any prefix operator already registered with the same
+    /// `operator` string is replaced.
+    pub fn add_prefix_operator(&self, operator: &str, precedence: u32, mapping: Box<dyn Fn(T) -> T>) {
+        self.table.borrow_mut().add_prefix(operator, PrefixOperator { precedence, mapping });
+    }
+
+    /// `expression_parser` returns a parser that parses an expression using the term parser and the operators in the table.
+    ///
+    /// # Errors
+    /// The parser will return a `ParserFailure` with an `Error` severity if the expression cannot be started, which is the case when
+    /// the term parser fails without changing the parser state and no prefix operator matches. Any failure after an operator has been
+    /// parsed is returned as a `FatalError`, including `"operator conflicts with associativity"` failures, which are reported when two
+    /// operators with the same precedence follow each other but their associativity does not allow them to be grouped.
+    pub fn expression_parser(&self) -> Parser<T> {
+        let opp = self.clone();
+
+        let parser_fn =
+            Box::new(
+                move |state: &mut ParserState| {
+                    let table = opp.table.borrow();
+                    table.parse_expression(&opp, state, 0, None)
+                }
+            );
+
+        Parser::new(parser_fn)
+    }
+}
+
+impl<T> OperatorTable<T> {
+    fn add_prefix(&mut self, operator: &str, prefix: PrefixOperator<T>) {
+        self.prefix.retain(|(existing, _)| existing != operator);
+        self.prefix.push((operator.to_string(), prefix));
+
+        let keys: Vec<&str> = self.prefix.iter().map(|(key, _)| key.as_str()).collect();
+        self.prefix_trie = Trie::new(&keys);
+    }
+
+    fn add_after_term(&mut self, operator: &str, after_term: AfterTermOperator<T>) {
+        self.after_term.retain(|(existing, _)| existing != operator);
+        self.after_term.push((operator.to_string(), after_term));
+
+        let keys: Vec<&str> = self.after_term.iter().map(|(key, _)| key.as_str()).collect();
+        self.after_term_trie = Trie::new(&keys);
+    }
+
+    fn parse_expression(&self, opp: &OperatorPrecedenceParser<T>, state: &mut ParserState, min_precedence: u64, parent: Option<usize>) -> ParserResult<T> {
+        let mut result = self.parse_prefix_or_term(opp, state)?.get_result();
+        let mut neighbor = parent;
+
+        while let Some((length, index)) = self.after_term_trie.longest_match(state.get_remaining_input(), |_| true) {
+            let (operator, after_term) = &self.after_term[index];
+
+            if u64::from(after_term.precedence()) < min_precedence {
+                break;
+            }
+
+            if let Some(neighbor) = neighbor {
+                if self.conflicts(neighbor, index) {
+                    return Err(ParserFailure::new_fatal_err(
+                        "operator conflicts with associativity".to_string(),
+                        Some(operator.clone()),
+                        state.get_position()
+                    ))
+                }
+            }
+
+            state.move_state_forward(length)?;
+            self.parse_after_operator(state)?;
+
+            result = match after_term {
+                AfterTermOperator::Postfix { mapping, .. } => mapping(result),
+                AfterTermOperator::Infix { precedence, associativity, mapping } => {
+                    let right = self.parse_operand(opp, state, *precedence, *associativity, index)?;
+                    neighbor = Some(index);
+                    mapping(result, right)
+                },
+                AfterTermOperator::Ternary { right: right_operator, precedence, associativity, mapping } => {
+                    let middle = self.parse_expression(opp, state, 0, None).map_err(|failure| failure.to_fatal_err())?;
+                    self.parse_operator_string(state, right_operator)?;
+                    let right = self.parse_operand(opp, state, *precedence, *associativity, index)?;
+                    neighbor = Some(index);
+                    mapping(result, middle.get_result(), right)
+                },
+            };
+        }
+
+        Ok(ParserSuccess::new(result, state.get_position()))
+    }
+
+    fn parse_prefix_or_term(&self, opp: &OperatorPrecedenceParser<T>, state: &mut ParserState) -> ParserResult<T> {
+        match self.prefix_trie.longest_match(state.get_remaining_input(), |_| true) {
+            Some((length, index)) => {
+                let (_, prefix) = &self.prefix[index];
+
+                state.move_state_forward(length)?;
+                self.parse_after_operator(state)?;
+
+                let operand = self.parse_expression(opp, state, u64::from(prefix.precedence) + 1, None)
+                    .map_err(|failure| failure.to_fatal_err())?;
+
+                Ok(ParserSuccess::new((prefix.mapping)(operand.get_result()), state.get_position()))
+            },
+            None => self.parse_term(opp, state),
+        }
+    }
+
+    fn parse_term(&self, opp: &OperatorPrecedenceParser<T>, state: &mut ParserState) -> ParserResult<T> {
+        match self.term {
+            Term::Simple(term) => term().parse(state),
+            Term::Recursive(term) => term(opp).parse(state),
+        }
+    }
+
+    fn parse_operand(&self, opp: &OperatorPrecedenceParser<T>, state: &mut ParserState, precedence: u32, associativity: Associativity, index: usize) -> Result<T, ParserFailure> {
+        let min_precedence = match associativity {
+            Associativity::Right => u64::from(precedence),
+            Associativity::Left | Associativity::None => u64::from(precedence) + 1,
+        };
+
+        self.parse_expression(opp, state, min_precedence, Some(index))
+            .map(|success| success.get_result())
+            .map_err(|failure| failure.to_fatal_err())
+    }
+
+    fn parse_operator_string(&self, state: &mut ParserState, operator: &str) -> Result<(), ParserFailure> {
+        match state.get_slice(operator.len()) {
+            Some(s) if s == operator => {
+                state.move_state_forward(operator.len())?;
+                self.parse_after_operator(state)
+            },
+            actual => {
+                Err(ParserFailure::new_fatal_err(
+                    operator.to_string(),
+                    actual,
+                    state.get_position()
+                ))
+            },
+        }
+    }
+
+    fn parse_after_operator(&self, state: &mut ParserState) -> Result<(), ParserFailure> {
+        (self.after_operator)().parse(state)
+            .map(|_| ())
+            .map_err(|failure| failure.to_fatal_err())
+    }
+
+    fn conflicts(&self, neighbor: usize, index: usize) -> bool {
+        let (_, previous) = &self.after_term[neighbor];
+        let (_, next) = &self.after_term[index];
+
+        match (previous.associativity(), next.associativity()) {
+            (Some(previous_associativity), Some(next_associativity)) if previous.precedence() == next.precedence() => {
+                previous_associativity != next_associativity || next_associativity == Associativity::None
+            },
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,377 @@
+use super::{ParserState, ParserSuccess, ParserFailure, Parser};
+
+/// `PermutationField` is one of the parsers passed to the `permutation_*` combinators. It is created with either `required_field`
+/// or `optional_field`, and its parser may be applied any number of times while the permutation is parsed.
+pub struct PermutationField<T>
+where T: 'static
+{
+    parser: Box<dyn Fn() -> Parser<T>>,
+    default: Option<T>,
+}
+
+/// `required_field` creates a `PermutationField` that must be parsed exactly once by a `permutation_*` combinator.
+pub fn required_field<T>(parser: fn() -> Parser<T>) -> PermutationField<T>
+where T: 'static
+{
+    PermutationField { parser: Box::new(parser), default: None }
+}
+
+/// `optional_field` creates a `PermutationField` that may be parsed at most once by a `permutation_*` combinator. The result
+/// is `Some` value if the field was parsed and `None` otherwise.
+pub fn optional_field<T>(parser: fn() -> Parser<T>) -> PermutationField<Option<T>>
+where T: 'static
+{
+    PermutationField { parser: Box::new(move || parser().map(Box::new(Some))), default: Some(None) }
+}
+
+/// `permutation_2` applies the parsers of the fields `f1` and `f2` in any order, each at most once, until none of the remaining
+/// fields can be parsed. The values parsed are passed to the two parameter function `f` in the order the fields were declared.
+///
+/// # Errors
+/// `permutation_2` will return a `ParserFailure` if any field parser fails with a `FatalError`, or if a required field is missing, in which
+/// case the failure of that field's parser is returned. A missing field is an `Error` if no field was parsed, and a `FatalError` otherwise.
+/// If a field that has already been parsed appears again, `permutation_2` returns a `FatalError` at the position of the duplicate.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_width() -> Parser<u32> {
+/// #     p_string(String::from("w=")).take_next(p_u32()).take_prev(ws())
+/// # }
+/// #
+/// # fn p_height() -> Parser<u32> {
+/// #     p_string(String::from("h=")).take_next(p_u32()).take_prev(ws())
+/// # }
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     (10, Some(20)),
+///     Position::new(1, 10, 9))
+/// );
+///
+/// let actual = permutation_2(
+///     required_field(p_width),
+///     optional_field(p_height),
+///     Box::new(|width, height| (width, height))
+/// ).run(String::from("h=20 w=10"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+pub fn permutation_2<T, U, V>(f1: PermutationField<T>, f2: PermutationField<U>, f: Box<dyn Fn (T, U) -> V>) -> Parser<V>
+where T: 'static, U: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let mut s1 = Slot::new(f1);
+                let mut s2 = Slot::new(f2);
+
+                apply_parsers(&mut [&mut s1, &mut s2], state)?;
+
+                let result =
+                    f(
+                        s1.take(),
+                        s2.take()
+                    );
+
+                Ok(ParserSuccess::new(result, state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `permutation_3` applies the parsers of the fields `f1`, `f2`, and `f3` in any order, each at most once, until none of the remaining
+/// fields can be parsed. The values parsed are passed to the three parameter function `f` in the order the fields were declared.
+///
+/// # Errors
+/// `permutation_3` will return a `ParserFailure` if any field parser fails with a `FatalError`, or if a required field is missing, in which
+/// case the failure of that field's parser is returned. A missing field is an `Error` if no field was parsed, and a `FatalError` otherwise.
+/// If a field that has already been parsed appears again, `permutation_3` returns a `FatalError` at the position of the duplicate.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_verbose() -> Parser<bool> {
+/// #     p_string(String::from("-v")).then_return(true).take_prev(ws())
+/// # }
+/// #
+/// # fn p_output() -> Parser<String> {
+/// #     p_string(String::from("-o ")).take_next(many_1_letter()).take_prev(ws())
+/// # }
+/// #
+/// # fn p_jobs() -> Parser<u32> {
+/// #     p_string(String::from("-j")).take_next(p_u32()).take_prev(ws())
+/// # }
+/// #
+/// let expected = Err(ParserFailure::new_fatal_err(
+///     String::from("each permutation field at most once"),
+///     Some(String::from("-v ")),
+///     Position::new(1, 7, 6))
+/// );
+///
+/// let actual = permutation_3(
+///     optional_field(p_verbose),
+///     required_field(p_output),
+///     optional_field(p_jobs),
+///     Box::new(|verbose, output, jobs| (verbose, output, jobs))
+/// ).run(String::from("-v -j4-v -o out"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+pub fn permutation_3<T, U, V, W>(f1: PermutationField<T>, f2: PermutationField<U>, f3: PermutationField<V>, f: Box<dyn Fn (T, U, V) -> W>) -> Parser<W>
+where T: 'static, U: 'static, V: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let mut s1 = Slot::new(f1);
+                let mut s2 = Slot::new(f2);
+                let mut s3 = Slot::new(f3);
+
+                apply_parsers(&mut [&mut s1, &mut s2, &mut s3], state)?;
+
+                let result =
+                    f(
+                        s1.take(),
+                        s2.take(),
+                        s3.take()
+                    );
+
+                Ok(ParserSuccess::new(result, state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `permutation_4` applies the parsers of the fields `f1`, `f2`, `f3`, and `f4` in any order, each at most once, until none of the remaining
+/// fields can be parsed. The values parsed are passed to the four parameter function `f` in the order the fields were declared.
+///
+/// # Errors
+/// `permutation_4` will return a `ParserFailure` if any field parser fails with a `FatalError`, or if a required field is missing, in which
+/// case the failure of that field's parser is returned. A missing field is an `Error` if no field was parsed, and a `FatalError` otherwise.
+/// If a field that has already been parsed appears again, `permutation_4` returns a `FatalError` at the position of the duplicate.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_attribute(name: &str) -> Parser<String> {
+/// #     p_string(format!("{}=", name)).take_next(many_1_letter()).take_prev(ws())
+/// # }
+/// #
+/// let expected = Err(ParserFailure::new_fatal_err(
+///     String::from("id="),
+///     None,
+///     Position::new(1, 20, 19))
+/// );
+///
+/// let actual = permutation_4(
+///     required_field(|| p_attribute("id")),
+///     required_field(|| p_attribute("src")),
+///     optional_field(|| p_attribute("alt")),
+///     optional_field(|| p_attribute("title")),
+///     Box::new(|id, src, alt, title| (id, src, alt, title))
+/// ).run(String::from("alt=Photo src=photo"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+pub fn permutation_4<T, U, V, W, X>(f1: PermutationField<T>, f2: PermutationField<U>, f3: PermutationField<V>, f4: PermutationField<W>, f: Box<dyn Fn (T, U, V, W) -> X>) -> Parser<X>
+where T: 'static, U: 'static, V: 'static, W: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let mut s1 = Slot::new(f1);
+                let mut s2 = Slot::new(f2);
+                let mut s3 = Slot::new(f3);
+                let mut s4 = Slot::new(f4);
+
+                apply_parsers(&mut [&mut s1, &mut s2, &mut s3, &mut s4], state)?;
+
+                let result =
+                    f(
+                        s1.take(),
+                        s2.take(),
+                        s3.take(),
+                        s4.take()
+                    );
+
+                Ok(ParserSuccess::new(result, state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+/// `permutation_5` applies the parsers of the fields `f1`, `f2`, `f3`, `f4`, and `f5` in any order, each at most once, until none of the
+/// remaining fields can be parsed. The values parsed are passed to the five parameter function `f` in the order the fields were declared.
+///
+/// # Errors
+/// `permutation_5` will return a `ParserFailure` if any field parser fails with a `FatalError`, or if a required field is missing, in which
+/// case the failure of that field's parser is returned. A missing field is an `Error` if no field was parsed, and a `FatalError` otherwise.
+/// If a field that has already been parsed appears again, `permutation_5` returns a `FatalError` at the position of the duplicate.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_field(name: &str) -> Parser<u32> {
+/// #     p_string(format!("{}:", name)).take_next(p_u32()).take_prev(ws())
+/// # }
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     (1, 2, None, 4, Some(5)),
+///     Position::new(1, 16, 15))
+/// );
+///
+/// let actual = permutation_5(
+///     required_field(|| p_field("a")),
+///     required_field(|| p_field("b")),
+///     optional_field(|| p_field("c")),
+///     required_field(|| p_field("d")),
+///     optional_field(|| p_field("e")),
+///     Box::new(|a, b, c, d, e| (a, b, c, d, e))
+/// ).run(String::from("e:5 d:4 b:2 a:1"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+pub fn permutation_5<T, U, V, W, X, Y>(f1: PermutationField<T>, f2: PermutationField<U>, f3: PermutationField<V>, f4: PermutationField<W>, f5: PermutationField<X>, f: Box<dyn Fn (T, U, V, W, X) -> Y>) -> Parser<Y>
+where T: 'static, U: 'static, V: 'static, W: 'static, X: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let mut s1 = Slot::new(f1);
+                let mut s2 = Slot::new(f2);
+                let mut s3 = Slot::new(f3);
+                let mut s4 = Slot::new(f4);
+                let mut s5 = Slot::new(f5);
+
+                apply_parsers(&mut [&mut s1, &mut s2, &mut s3, &mut s4, &mut s5], state)?;
+
+                let result =
+                    f(
+                        s1.take(),
+                        s2.take(),
+                        s3.take(),
+                        s4.take(),
+                        s5.take()
+                    );
+
+                Ok(ParserSuccess::new(result, state.get_position()))
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+trait PermutationSlot {
+    fn is_filled(&self) -> bool;
+    fn apply(&mut self, state: &mut ParserState) -> Result<bool, ParserFailure>;
+    fn take_missing_failure(&mut self) -> Option<ParserFailure>;
+}
+
+struct Slot<T>
+where T: 'static
+{
+    field: PermutationField<T>,
+    result: Option<T>,
+    failure: Option<ParserFailure>,
+}
+
+impl<T> Slot<T> {
+    fn new(field: PermutationField<T>) -> Slot<T> {
+        Slot { field, result: None, failure: None }
+    }
+
+    fn take(self) -> T {
+        self.result
+            .or(self.field.default)
+            .expect("required permutation fields are checked before the results are taken")
+    }
+}
+
+impl<T> PermutationSlot for Slot<T> {
+    fn is_filled(&self) -> bool {
+        self.result.is_some()
+    }
+
+    fn apply(&mut self, state: &mut ParserState) -> Result<bool, ParserFailure> {
+        match (self.field.parser)().parse(state) {
+            Ok(success) => {
+                if self.result.is_none() {
+                    self.result = Some(success.get_result());
+                }
+
+                Ok(true)
+            },
+            Err(failure) => {
+                if failure.is_fatal() {
+                    return Err(failure)
+                }
+
+                self.failure = Some(failure);
+                Ok(false)
+            },
+        }
+    }
+
+    fn take_missing_failure(&mut self) -> Option<ParserFailure> {
+        if self.result.is_some() || self.field.default.is_some() {
+            return None
+        }
+
+        self.failure.take()
+    }
+}
+
+fn apply_parsers(slots: &mut [&mut dyn PermutationSlot], state: &mut ParserState) -> Result<(), ParserFailure> {
+    let start = state.get_index();
+
+    'fields: loop {
+        for slot in slots.iter_mut().filter(|slot| !slot.is_filled()) {
+            if slot.apply(state)? {
+                continue 'fields;
+            }
+        }
+
+        for slot in slots.iter_mut().filter(|slot| slot.is_filled()) {
+            let duplicate_start = state.get_index();
+            let duplicate_position = state.get_position();
+
+            if slot.apply(state)? && state.get_index() != duplicate_start {
+                let duplicate = state.get_slice_since(duplicate_start);
+                state.revert_to(duplicate_start);
+
+                return Err(ParserFailure::new_fatal_err(
+                    "each permutation field at most once".to_string(),
+                    Some(duplicate),
+                    duplicate_position
+                ))
+            }
+
+            state.revert_to(duplicate_start);
+        }
+
+        break;
+    }
+
+    for slot in slots.iter_mut() {
+        if let Some(failure) = slot.take_missing_failure() {
+            return if state.get_index() == start {
+                Err(failure)
+            } else {
+                Err(failure.to_fatal_err())
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -443,4 +443,116 @@ pub fn tuple_5<T, U, V, W, X>(p1: Parser<T>, p2: Parser<U>, p3: Parser<V>, p4: P
 
 fn apply_parser<T>(p: Parser<T>, state: &mut ParserState) -> ParserResult<T> {
     p.parse(state).map_err(|failure| failure.to_fatal_err())
-}
\ No newline at end of file
+}
+
+/// `pipe!` applies any number of parsers in sequence. If all parsers are successful, the values parsed are used as the arguments
+/// for the function that follows `=>`. A parser written as `_ = parser` is applied in sequence as well, but its value is discarded
+/// and is not passed to the function.
+/// 
+/// # Errors
+/// `pipe!` will return a `ParserFailure` if any of the parsers fails. As with `pipe_2` through `pipe_5`, the failure will be an `Error`
+/// if the first parser fails without changing the parser state, and will be a `FatalError` if any of the following parsers fail 
+/// or if the first parser fails after changing the parser state.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("2024-05-17 12:30:59"),
+///     Position::new(1, 19, 18))
+/// );
+/// 
+/// let actual = pipe!(
+///     p_u32(), _ = p_char('-'), p_u32(), _ = p_char('-'), p_u32(),
+///     _ = ws(),
+///     p_u32(), _ = p_char(':'), p_u32(), _ = p_char(':'), p_u32()
+///     => |year, month, day, hour, minute, second| 
+///         format!("{}-{:02}-{:02} {}:{}:{}", year, month, day, hour, minute, second)
+/// ).run(String::from("2024-5-17 12:30:59"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+#[macro_export]
+macro_rules! pipe {
+    ($($elements:tt)*) => {
+        $crate::__sequence!(
+            @first [v1 v2 v3 v4 v5 v6 v7 v8 v9 v10 v11 v12 v13 v14 v15 v16 v17 v18 v19 v20 v21 v22 v23 v24] ; 
+            $($elements)*
+        )
+    };
+}
+
+/// `seq!` applies any number of parsers in sequence. If all parsers are successful, the values parsed are returned in a tuple.
+/// A parser written as `_ = parser` is applied in sequence as well, but its value is discarded and is not included in the tuple.
+/// 
+/// # Errors
+/// `seq!` will return a `ParserFailure` if any of the parsers fails. As with `tuple_2` through `tuple_5`, the failure will be an `Error`
+/// if the first parser fails without changing the parser state, and will be a `FatalError` if any of the following parsers fail 
+/// or if the first parser fails after changing the parser state.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_fatal_err(
+///     String::from("integral value"),
+///     None,
+///     Position::new(1, 6, 5))
+/// );
+/// 
+/// let actual = seq!(
+///     p_char('('), _ = ws(), p_u32(), _ = ws(), _ = p_char(','), _ = ws(), p_u32(), _ = ws(), p_char(')')
+/// ).run(String::from("( 1, x)"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+#[macro_export]
+macro_rules! seq {
+    ($($elements:tt)*) => {
+        $crate::__sequence!(
+            @first [v1 v2 v3 v4 v5 v6 v7 v8 v9 v10 v11 v12 v13 v14 v15 v16 v17 v18 v19 v20 v21 v22 v23 v24] ; 
+            $($elements)* => @tuple
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sequence {
+    (@first [$($free:ident)*] ; _ = $p:expr , $($rest:tt)*) => {
+        $crate::__sequence!(@next [$p] [_] [] [$($free)*] ; $($rest)*)
+    };
+    (@first [$($free:ident)*] ; _ = $p:expr => $($rest:tt)*) => {
+        $crate::__sequence!(@next [$p] [_] [] [$($free)*] ; => $($rest)*)
+    };
+    (@first [$id:ident $($free:ident)*] ; $p:expr , $($rest:tt)*) => {
+        $crate::__sequence!(@next [$p] [$id] [$id] [$($free)*] ; $($rest)*)
+    };
+    (@first [$id:ident $($free:ident)*] ; $p:expr => $($rest:tt)*) => {
+        $crate::__sequence!(@next [$p] [$id] [$id] [$($free)*] ; => $($rest)*)
+    };
+    (@next [$acc:expr] [$pat:pat] [$($used:ident)*] [$($free:ident)*] ; => @tuple) => {
+        $acc.map(::std::boxed::Box::new(|$pat| ($($used,)*)))
+    };
+    (@next [$acc:expr] [$pat:pat] [$($used:ident)*] [$($free:ident)*] ; => $f:expr) => {
+        {
+            let f = $f;
+            $acc.map(::std::boxed::Box::new(move |$pat| f($($used),*)))
+        }
+    };
+    (@next [$acc:expr] [$pat:pat] [$($used:ident)*] [$($free:ident)*] ; _ = $p:expr , $($rest:tt)*) => {
+        $crate::__sequence!(@next [$acc.take_prev($p)] [$pat] [$($used)*] [$($free)*] ; $($rest)*)
+    };
+    (@next [$acc:expr] [$pat:pat] [$($used:ident)*] [$($free:ident)*] ; _ = $p:expr => $($rest:tt)*) => {
+        $crate::__sequence!(@next [$acc.take_prev($p)] [$pat] [$($used)*] [$($free)*] ; => $($rest)*)
+    };
+    (@next [$acc:expr] [$pat:pat] [$($used:ident)*] [$id:ident $($free:ident)*] ; $p:expr , $($rest:tt)*) => {
+        $crate::__sequence!(@next [$acc.and($p)] [($pat, $id)] [$($used)* $id] [$($free)*] ; $($rest)*)
+    };
+    (@next [$acc:expr] [$pat:pat] [$($used:ident)*] [$id:ident $($free:ident)*] ; $p:expr => $($rest:tt)*) => {
+        $crate::__sequence!(@next [$acc.and($p)] [($pat, $id)] [$($used)* $id] [$($free)*] ; => $($rest)*)
+    };
+}
@@ -0,0 +1,271 @@
+use std::any::TypeId;
+use std::collections::{HashMap, HashSet};
+
+use super::{ParserState, ParserFailure, ParserResult, Parser};
+use super::super::state::{MemoEntry, MemoId};
+
+/// `RuleId` identifies a rule by the address of the function that builds its parser and the type of the value it returns.
+type RuleId = (usize, TypeId);
+
+/// `LeftRecursion` records a rule while it is being applied at an index of the input. If the rule turns out to be left recursive,
+/// the `head` is the rule at which the left recursion was detected.
+struct LeftRecursion {
+    rule: RuleId,
+    head: Option<usize>,
+}
+
+/// `Head` is the rule at which a left recursion was detected. The `involved` rules are the rules applied between the head and its
+/// left recursive application, and the `eval` rules are the involved rules that have yet to be re-applied while the seed is grown.
+struct Head {
+    rule: RuleId,
+    involved: HashSet<RuleId>,
+    eval: HashSet<RuleId>,
+}
+
+/// `RuleTable` keeps track of the rules that are being applied, so that `rule` can detect and grow left recursive rules following the approach described by
+/// Warth, Douglass and Millstein in "Packrat Parsers Can Support Left Recursion". The results of the rules, including the seeds of left recursive rules,
+/// are stored in the memo table of the parser state used by `memoize`.
+#[derive(Default)]
+pub(in crate::parser) struct RuleTable {
+    in_progress: HashMap<(RuleId, usize), usize>,
+    left_recursions: Vec<LeftRecursion>,
+    stack: Vec<usize>,
+    heads: Vec<Head>,
+    heads_at: HashMap<usize, usize>,
+}
+
+/// `rule` applies the parser returned by the function `f`, calling `f` only when the parser is applied. This allows rules to refer to themselves
+/// and to rules that are declared later, and unlike calling `f` directly, `rule` also supports left recursive rules such as `expr := expr '-' term | term`.
+/// When a rule is applied again at the same position before it has finished, the application fails so that the other alternatives of the rule can be
+/// tried, and the result of the first successful alternative is used as a seed. The rule is then applied repeatedly, each time using the previous result
+/// for the left recursive application, until the input consumed stops growing. This produces left associative results for both directly and indirectly
+/// left recursive rules, as long as every rule in the recursion is applied with `rule`.
+///
+/// The results of a `rule` are memoized by the function `f` and the position where it was applied, so applying the same rule at the same position
+/// after backtracking returns the cached result. Rules are identified by the address of `f`, so two functions that the compiler merges because their
+/// bodies compile to the same code are treated as the same rule and share their results, and a function may be seen as more than one rule if it has
+/// more than one address. Neither changes the value a grammar parses, as long as each rule is a function without side effects.
+///
+/// # Errors
+/// `rule` returns the `ParserFailure` of the parser returned by `f`. If the parser only fails because of left recursion, the failure is an `Error`
+/// at the position where the rule was applied.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// fn p_expr() -> Parser<i32> {
+///     attempt(rule(p_expr).take_prev(p_char('-')).and(p_i32()))
+///         .map(Box::new(|(x, y)| x - y))
+///         .or(p_i32())
+/// }
+///
+/// let expected = Ok(ParserSuccess::new(
+///     2,
+///     Position::new(1, 6, 5)
+/// ));
+///
+/// let actual = rule(p_expr)
+///     .run(String::from("5-2-1"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+pub fn rule<T>(f: fn() -> Parser<T>) -> Parser<T>
+where T: Clone + 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                apply_rule(f, state)
+            }
+        );
+
+    Parser::new(parser_fn)
+}
+
+fn apply_rule<T>(f: fn() -> Parser<T>, state: &mut ParserState) -> ParserResult<T>
+where T: Clone + 'static
+{
+    let id = (f as usize, TypeId::of::<T>());
+    let start = state.get_index();
+
+    if let Some(result) = recall(id, f, start, state) {
+        return result
+    }
+
+    let table = state.get_rule_table();
+    let lr = table.left_recursions.len();
+
+    table.left_recursions.push(LeftRecursion { rule: id, head: None });
+    table.stack.push(lr);
+    table.in_progress.insert((id, start), lr);
+
+    let result = f().parse(state);
+    let end = state.get_index();
+
+    let table = state.get_rule_table();
+    table.stack.pop();
+
+    let head = match table.left_recursions[lr].head {
+        Some(head) => head,
+        None => {
+            table.in_progress.remove(&(id, start));
+            set_rule_memo(f, start, &result, end, state);
+            return result
+        },
+    };
+
+    if table.heads[head].rule != id {
+        set_rule_memo(f, start, &result, end, state);
+        return result
+    }
+
+    table.in_progress.remove(&(id, start));
+    set_rule_memo(f, start, &result, end, state);
+
+    if result.is_ok() {
+        grow_seed(f, start, head, state)
+    } else {
+        result
+    }
+}
+
+fn recall<T>(id: RuleId, f: fn() -> Parser<T>, start: usize, state: &mut ParserState) -> Option<ParserResult<T>>
+where T: Clone + 'static
+{
+    let is_memoized = get_rule_memo(f, start, state).is_some();
+    let table = state.get_rule_table();
+
+    if let Some(&head) = table.heads_at.get(&start) {
+        let is_memoized = is_memoized || table.in_progress.contains_key(&(id, start));
+        let head = &mut table.heads[head];
+
+        if !is_memoized && head.rule != id && !head.involved.contains(&id) {
+            return Some(Err(left_recursion_failure(state)))
+        }
+
+        if head.eval.remove(&id) {
+            let result = f().parse(state);
+            let end = state.get_index();
+
+            state.get_rule_table().in_progress.remove(&(id, start));
+            set_rule_memo(f, start, &result, end, state);
+
+            return Some(result)
+        }
+    }
+
+    let table = state.get_rule_table();
+
+    match table.in_progress.get(&(id, start)) {
+        Some(&lr) => set_up_left_recursion(id, lr, table),
+        None if !is_memoized => return None,
+        None => (),
+    }
+
+    let (result, end) = match get_rule_memo(f, start, state) {
+        Some(entry) => (entry.result.clone(), entry.end),
+        None => (Err(left_recursion_failure(state)), start),
+    };
+
+    match move_state_to(end, state) {
+        Ok(()) => Some(result),
+        Err(failure) => Some(Err(failure)),
+    }
+}
+
+fn set_up_left_recursion(id: RuleId, lr: usize, table: &mut RuleTable) {
+    let head = match table.left_recursions[lr].head {
+        Some(head) => head,
+        None => {
+            table.heads.push(Head { rule: id, involved: HashSet::new(), eval: HashSet::new() });
+            table.left_recursions[lr].head = Some(table.heads.len() - 1);
+            table.heads.len() - 1
+        },
+    };
+
+    for &s in table.stack.iter().rev() {
+        if table.left_recursions[s].head == Some(head) {
+            break;
+        }
+
+        table.left_recursions[s].head = Some(head);
+
+        let rule = table.left_recursions[s].rule;
+        table.heads[head].involved.insert(rule);
+    }
+}
+
+fn grow_seed<T>(f: fn() -> Parser<T>, start: usize, head: usize, state: &mut ParserState) -> ParserResult<T>
+where T: Clone + 'static
+{
+    state.get_rule_table().heads_at.insert(start, head);
+
+    let mut best =
+        get_rule_memo(f, start, state)
+            .map(|entry| entry.end)
+            .unwrap_or(start);
+
+    loop {
+        state.revert_to(start);
+
+        let table = state.get_rule_table();
+        table.heads[head].eval = table.heads[head].involved.clone();
+
+        let result = f().parse(state);
+        let end = state.get_index();
+
+        if result.is_err() || end <= best {
+            break;
+        }
+
+        best = end;
+        set_rule_memo(f, start, &result, end, state);
+    }
+
+    state.get_rule_table().heads_at.remove(&start);
+
+    let result = get_rule_memo(f, start, state).map(|entry| entry.result.clone());
+
+    state.revert_to(start);
+    move_state_to(best, state)?;
+
+    result.unwrap_or_else(|| Err(left_recursion_failure(state)))
+}
+
+/// `get_rule_memo` returns the result of the rule `f` applied at the index `start` from the memo table of the parser state, which is either
+/// the final result of the rule or, while a left recursive rule is being applied, its seed.
+fn get_rule_memo<T>(f: fn() -> Parser<T>, start: usize, state: &ParserState) -> Option<&MemoEntry<T>>
+where T: 'static
+{
+    state.get_memo::<T>(&MemoId::Rule(f as usize), start)
+}
+
+/// `set_rule_memo` stores the result of the rule `f` applied at the index `start` in the memo table of the parser state. Unlike `memoize`, 
+/// rules do not record the cuts made while they were applied.
+fn set_rule_memo<T>(f: fn() -> Parser<T>, start: usize, result: &ParserResult<T>, end: usize, state: &mut ParserState)
+where T: Clone + 'static
+{
+    let entry = MemoEntry { result: result.clone(), end, cuts: 0 };
+    state.set_memo(&MemoId::Rule(f as usize), start, entry);
+}
+
+fn move_state_to(index: usize, state: &mut ParserState) -> Result<(), ParserFailure> {
+    let current = state.get_index();
+
+    if index > current {
+        state.move_state_forward(index - current)
+    } else {
+        state.revert_to(index);
+        Ok(())
+    }
+}
+
+fn left_recursion_failure(state: &ParserState) -> ParserFailure {
+    ParserFailure::new_err(
+        "rule without left recursion".to_string(),
+        None,
+        state.get_position()
+    )
+}
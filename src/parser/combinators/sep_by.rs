@@ -1,4 +1,4 @@
-use super::{ParserState, ParserSuccess, ParserFailure, Parser};
+use super::{ParserState, ParserSuccess, ParserFailure, Parser, no_progress_failure};
 
 /// `sep_by` takes two parsers and applies the first parser (`parser`) followed by the second parser (`separator`) repeatedly until one of them fails.
 /// Once either parser fails, all values parsed by the `parser` are returned in a Vector as a `ParserSuccess`.
@@ -6,6 +6,7 @@ use super::{ParserState, ParserSuccess, ParserFailure, Parser};
 /// 
 /// # Errors
 /// `sep_by` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `separator` fails having changed the parser state.
+/// A `FatalError` is also returned if the `parser` and the `separator` both succeed without consuming any input.
 /// 
 /// # Examples
 /// 
@@ -34,7 +35,7 @@ where U: 'static
     let parser_fn =
         Box::new(
             move |state: &mut ParserState| {
-                let results = apply_parser(parser, separator, state)?;
+                let results = apply_parser("sep_by", parser, separator, Separator::Between, state)?;
                 Ok(ParserSuccess::new(results, state.get_position()))
             }
         );
@@ -78,17 +79,8 @@ where U: 'static
     let parser_fn =
         Box::new(
             move |state: &mut ParserState| {
-                let results = apply_parser(parser, separator, state)?;
-
-                if results.len() == 0 {
-                    Err(ParserFailure::new_err(
-                        "value satisfying parser at least once".to_string(),
-                        None,
-                        state.get_position()
-                    ))
-                } else {
-                    Ok(ParserSuccess::new(results, state.get_position()))
-                }
+                let results = apply_parser_1("sep_by_1", parser, separator, Separator::Between, state)?;
+                Ok(ParserSuccess::new(results, state.get_position()))
             }
         );
     
@@ -129,7 +121,7 @@ where U: 'static
     let parser_fn =
         Box::new(
             move |state: &mut ParserState| {
-                let _ = apply_parser(parser, separator, state)?;
+                let _ = apply_parser("skip_sep_by", parser, separator, Separator::Between, state)?;
                 Ok(ParserSuccess::new((), state.get_position()))
             }
         );
@@ -173,56 +165,514 @@ where U: 'static
     let parser_fn =
         Box::new(
             move |state: &mut ParserState| {
-                if let Ok(_) = parser().parse(state) {
-                    match separator().parse(state) {
-                        Ok(_) => {
-                            let _ = apply_parser(parser, separator, state)?;
-                        },
-                        Err(failure) => {
-                            if failure.is_fatal() {
-                                return Err(failure);
-                            }
-                        }
-                    }
-
-                    return Ok(ParserSuccess::new((), state.get_position()))
-                }
+                let _ = apply_parser_1("skip_sep_by_1", parser, separator, Separator::Between, state)?;
+                Ok(ParserSuccess::new((), state.get_position()))
+            }
+        );
+    
+    Parser::new(parser_fn)
+}
+
+/// `sep_end_by` takes two parsers and applies the first parser (`parser`) followed by the second parser (`separator`) repeatedly until one of them fails.
+/// Once either parser fails, all values parsed by the `parser` are returned in a Vector as a `ParserSuccess`. The values may be followed
+/// by a trailing separator, which is consumed.
+/// If the `parser` fails on the first attempt, `sep_end_by` will return a `ParserSuccess` with an empty Vector.
+/// 
+/// # Errors
+/// `sep_end_by` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `separator` fails having changed the parser state.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_comma() -> Parser<char> {
+/// #     p_char(',')
+/// # }
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     vec![1,2,3], 
+///     Position::new(1, 7, 6))
+/// );
+/// 
+/// let actual = sep_end_by(
+///     p_u32, 
+///     p_comma
+/// ).run(String::from("1,2,3,"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn sep_end_by<T, U>(parser: fn() -> Parser<T>, separator: fn() -> Parser<U>) -> Parser<Vec<T>> 
+where U: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let results = apply_parser("sep_end_by", parser, separator, Separator::Between, state)?;
+                Ok(ParserSuccess::new(results, state.get_position()))
+            }
+        );
+    
+    Parser::new(parser_fn)
+}
+
+/// `sep_end_by_1` takes two parsers and applies the first parser (`parser`) followed by the second parser (`separator`) repeatedly until one of them fails.
+/// Once either parser fails, all values parsed by the `parser` are returned in a Vector as a `ParserSuccess`. The values may be followed
+/// by a trailing separator, which is consumed.
+/// 
+/// # Errors
+/// `sep_end_by_1` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `separator` fails having changed the parser state.
+/// Unlike `sep_end_by`, if the `parser` fails on the first attempt, `sep_end_by_1` will return a `ParserFailure`. The `parser` must succeed at least
+/// once for `sep_end_by_1` to return a `ParserSuccess`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_comma() -> Parser<char> {
+/// #     p_char(',')
+/// # }
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("value satisfying parser at least once"), 
+///     None,
+///     Position::new(1, 1, 0))
+/// );
+/// 
+/// let actual = sep_end_by_1(
+///     p_u32, 
+///     p_comma
+/// ).run(String::from(",1,2"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn sep_end_by_1<T, U>(parser: fn() -> Parser<T>, separator: fn() -> Parser<U>) -> Parser<Vec<T>> 
+where U: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let results = apply_parser_1("sep_end_by_1", parser, separator, Separator::Between, state)?;
+                Ok(ParserSuccess::new(results, state.get_position()))
+            }
+        );
+    
+    Parser::new(parser_fn)
+}
+
+/// `skip_sep_end_by` takes two parsers and applies the first parser (`parser`) followed by the second parser (`separator`) repeatedly until one of them fails.
+/// Once either parser fails, `()` is returned as a `ParserSuccess`. The values may be followed by a trailing separator, which is consumed.
+/// If the `parser` fails on the first attempt, `skip_sep_end_by` will return a `ParserSuccess` of `()`.
+/// 
+/// # Errors
+/// `skip_sep_end_by` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `separator` fails having changed the parser state.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_comma() -> Parser<char> {
+/// #     p_char(',')
+/// # }
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     (), 
+///     Position::new(1, 7, 6))
+/// );
+/// 
+/// let actual = skip_sep_end_by(
+///     p_u32, 
+///     p_comma
+/// ).run(String::from("1,2,3,"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn skip_sep_end_by<T, U>(parser: fn() -> Parser<T>, separator: fn() -> Parser<U>) -> Parser<()> 
+where U: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let _ = apply_parser("skip_sep_end_by", parser, separator, Separator::Between, state)?;
+                Ok(ParserSuccess::new((), state.get_position()))
+            }
+        );
+    
+    Parser::new(parser_fn)
+}
+
+/// `skip_sep_end_by_1` takes two parsers and applies the first parser (`parser`) followed by the second parser (`separator`) repeatedly until one of them fails.
+/// Once either parser fails, `()` is returned as a `ParserSuccess`. The values may be followed by a trailing separator, which is consumed.
+/// 
+/// # Errors
+/// `skip_sep_end_by_1` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `separator` fails having changed the parser state.
+/// Unlike `skip_sep_end_by`, if the `parser` fails on the first attempt, `skip_sep_end_by_1` will return a `ParserFailure`. The `parser` must succeed at least
+/// once for `skip_sep_end_by_1` to return a `ParserSuccess`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_comma() -> Parser<char> {
+/// #     p_char(',')
+/// # }
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     (), 
+///     Position::new(1, 3, 2))
+/// );
+/// 
+/// let actual = skip_sep_end_by_1(
+///     p_u32, 
+///     p_comma
+/// ).run(String::from("1,"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn skip_sep_end_by_1<T, U>(parser: fn() -> Parser<T>, separator: fn() -> Parser<U>) -> Parser<()> 
+where U: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let _ = apply_parser_1("skip_sep_end_by_1", parser, separator, Separator::Between, state)?;
+                Ok(ParserSuccess::new((), state.get_position()))
+            }
+        );
+    
+    Parser::new(parser_fn)
+}
+
+/// `end_by` takes two parsers and applies the first parser (`parser`) followed by the second parser (`separator`) repeatedly until the `parser` fails.
+/// Every value must be followed by the `separator`. Once the `parser` fails, all values parsed by the `parser` are returned in a Vector as a `ParserSuccess`.
+/// If the `parser` fails on the first attempt, `end_by` will return a `ParserSuccess` with an empty Vector.
+/// 
+/// # Errors
+/// `end_by` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `separator` fails having changed the parser state,
+/// or if the `separator` fails after the `parser` succeeded.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_semicolon() -> Parser<char> {
+/// #     p_char(';')
+/// # }
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     vec![1,2], 
+///     Position::new(1, 5, 4))
+/// );
+/// 
+/// let actual = end_by(
+///     p_u32, 
+///     p_semicolon
+/// ).run(String::from("1;2;"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn end_by<T, U>(parser: fn() -> Parser<T>, separator: fn() -> Parser<U>) -> Parser<Vec<T>> 
+where U: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let results = apply_parser("end_by", parser, separator, Separator::After, state)?;
+                Ok(ParserSuccess::new(results, state.get_position()))
+            }
+        );
+    
+    Parser::new(parser_fn)
+}
+
+/// `end_by_1` takes two parsers and applies the first parser (`parser`) followed by the second parser (`separator`) repeatedly until the `parser` fails.
+/// Every value must be followed by the `separator`. Once the `parser` fails, all values parsed by the `parser` are returned in a Vector as a `ParserSuccess`.
+/// 
+/// # Errors
+/// `end_by_1` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `separator` fails having changed the parser state,
+/// or if the `separator` fails after the `parser` succeeded. Unlike `end_by`, if the `parser` fails on the first attempt, `end_by_1` will return
+/// a `ParserFailure`. The `parser` must succeed at least once for `end_by_1` to return a `ParserSuccess`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_semicolon() -> Parser<char> {
+/// #     p_char(';')
+/// # }
+/// #
+/// let expected = Err(ParserFailure::new_fatal_err(
+///     String::from(";"), 
+///     None,
+///     Position::new(1, 4, 3))
+/// );
+/// 
+/// let actual = end_by_1(
+///     p_u32, 
+///     p_semicolon
+/// ).run(String::from("1;2"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn end_by_1<T, U>(parser: fn() -> Parser<T>, separator: fn() -> Parser<U>) -> Parser<Vec<T>> 
+where U: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let results = apply_parser_1("end_by_1", parser, separator, Separator::After, state)?;
+                Ok(ParserSuccess::new(results, state.get_position()))
+            }
+        );
+    
+    Parser::new(parser_fn)
+}
+
+/// `skip_end_by` takes two parsers and applies the first parser (`parser`) followed by the second parser (`separator`) repeatedly until the `parser` fails.
+/// Every value must be followed by the `separator`. Once the `parser` fails, `()` is returned as a `ParserSuccess`.
+/// If the `parser` fails on the first attempt, `skip_end_by` will return a `ParserSuccess` of `()`.
+/// 
+/// # Errors
+/// `skip_end_by` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `separator` fails having changed the parser state,
+/// or if the `separator` fails after the `parser` succeeded.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_semicolon() -> Parser<char> {
+/// #     p_char(';')
+/// # }
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     (), 
+///     Position::new(1, 5, 4))
+/// );
+/// 
+/// let actual = skip_end_by(
+///     p_u32, 
+///     p_semicolon
+/// ).run(String::from("1;2;"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn skip_end_by<T, U>(parser: fn() -> Parser<T>, separator: fn() -> Parser<U>) -> Parser<()> 
+where U: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let _ = apply_parser("skip_end_by", parser, separator, Separator::After, state)?;
+                Ok(ParserSuccess::new((), state.get_position()))
+            }
+        );
+    
+    Parser::new(parser_fn)
+}
+
+/// `skip_end_by_1` takes two parsers and applies the first parser (`parser`) followed by the second parser (`separator`) repeatedly until the `parser` fails.
+/// Every value must be followed by the `separator`. Once the `parser` fails, `()` is returned as a `ParserSuccess`.
+/// 
+/// # Errors
+/// `skip_end_by_1` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `separator` fails having changed the parser state,
+/// or if the `separator` fails after the `parser` succeeded. Unlike `skip_end_by`, if the `parser` fails on the first attempt, `skip_end_by_1` will
+/// return a `ParserFailure`. The `parser` must succeed at least once for `skip_end_by_1` to return a `ParserSuccess`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_semicolon() -> Parser<char> {
+/// #     p_char(';')
+/// # }
+/// #
+/// let expected = Err(ParserFailure::new_err(
+///     String::from("value satisfying parser at least once"), 
+///     None,
+///     Position::new(1, 1, 0))
+/// );
+/// 
+/// let actual = skip_end_by_1(
+///     p_u32, 
+///     p_semicolon
+/// ).run(String::from(";"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn skip_end_by_1<T, U>(parser: fn() -> Parser<T>, separator: fn() -> Parser<U>) -> Parser<()> 
+where U: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let _ = apply_parser_1("skip_end_by_1", parser, separator, Separator::After, state)?;
+                Ok(ParserSuccess::new((), state.get_position()))
+            }
+        );
+    
+    Parser::new(parser_fn)
+}
+
+/// `sep_by_fold` takes two parsers and applies the first parser (`parser`) followed by the second parser (`separator`) repeatedly until one of them fails,
+/// combining each value parsed by the `parser` with an accumulator using the function `f`, starting with `init`. The final value of the accumulator is
+/// returned as a `ParserSuccess`, without collecting the parsed values into a Vector.
+/// If the `parser` fails on the first attempt, `sep_by_fold` will return a `ParserSuccess` of `init`.
+/// 
+/// # Errors
+/// `sep_by_fold` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `separator` fails having changed the parser state.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// # fn p_comma() -> Parser<char> {
+/// #     p_char(',')
+/// # }
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     6, 
+///     Position::new(1, 6, 5))
+/// );
+/// 
+/// let actual = sep_by_fold(
+///     p_u32, 
+///     p_comma,
+///     0,
+///     Box::new(|sum, n| sum + n)
+/// ).run(String::from("1,2,3"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn sep_by_fold<T, U, A>(parser: fn() -> Parser<T>, separator: fn() -> Parser<U>, init: A, f: Box<dyn Fn(A, T) -> A>) -> Parser<A> 
+where U: 'static, A: 'static
+{
+    fold("sep_by_fold", parser, separator, init, f)
+}
+
+/// `sep_by_collect` takes two parsers and applies the first parser (`parser`) followed by the second parser (`separator`) repeatedly until one of them fails,
+/// extending a collection of type `C` with each value parsed by the `parser`. The collection is returned as a `ParserSuccess`. Any type implementing `Default`
+/// and `Extend`, such as a `String`, a `HashSet` or a `HashMap`, can be used as the collection.
+/// If the `parser` fails on the first attempt, `sep_by_collect` will return a `ParserSuccess` with an empty collection.
+/// 
+/// # Errors
+/// `sep_by_collect` will return a `ParserFailure` with a `FatalError` if either the `parser` or the `separator` fails having changed the parser state.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # use rusty_parsec::*;
+/// # use std::collections::HashMap;
+/// #
+/// # fn p_entry() -> Parser<(char, u32)> {
+/// #     letter().take_prev(p_char('=')).and(p_u32())
+/// # }
+/// #
+/// # fn p_comma() -> Parser<char> {
+/// #     p_char(',')
+/// # }
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     HashMap::from([('a', 1), ('b', 2)]), 
+///     Position::new(1, 8, 7))
+/// );
+/// 
+/// let actual = sep_by_collect::<_, _, HashMap<char, u32>>(
+///     p_entry, 
+///     p_comma
+/// ).run(String::from("a=1,b=2"));
+/// 
+/// assert_eq!(actual, expected);
+/// ```
+pub fn sep_by_collect<T, U, C>(parser: fn() -> Parser<T>, separator: fn() -> Parser<U>) -> Parser<C> 
+where U: 'static, C: Default + Extend<T> + 'static
+{
+    fold("sep_by_collect", parser, separator, C::default(), Box::new(|mut results: C, result| { results.extend(std::iter::once(result)); results }))
+}
 
-                Err(ParserFailure::new_err(
-                    "value satisfying parser at least once".to_string(),
-                    None,
-                    state.get_position()
-                ))
+fn fold<T, U, A>(name: &'static str, parser: fn() -> Parser<T>, separator: fn() -> Parser<U>, init: A, f: Box<dyn Fn(A, T) -> A>) -> Parser<A> 
+where U: 'static, A: 'static
+{
+    let parser_fn =
+        Box::new(
+            move |state: &mut ParserState| {
+                let (result, _) = fold_parser(name, parser, separator, Separator::Between, init, &f, state)?;
+                Ok(ParserSuccess::new(result, state.get_position()))
             }
         );
     
     Parser::new(parser_fn)
 }
 
-fn apply_parser<T, U>(parser: fn() -> Parser<T>, separator: fn() -> Parser<U>, state: &mut ParserState) -> Result<Vec<T>, ParserFailure> {
-    let mut results: Vec<T> = Vec::new();
-    let mut parser_succeeds = true;
+#[derive(PartialEq)]
+enum Separator {
+    Between,
+    After,
+}
+
+fn apply_parser<T, U>(name: &str, parser: fn() -> Parser<T>, separator: fn() -> Parser<U>, placement: Separator, state: &mut ParserState) -> Result<Vec<T>, ParserFailure> {
+    fold_parser(name, parser, separator, placement, Vec::new(), &|mut results: Vec<T>, result| { results.push(result); results }, state)
+        .map(|(results, _)| results)
+}
+
+fn fold_parser<T, U, A>(name: &str, parser: fn() -> Parser<T>, separator: fn() -> Parser<U>, placement: Separator, init: A, f: &dyn Fn(A, T) -> A, state: &mut ParserState) -> Result<(A, usize), ParserFailure> {
+    let mut acc = init;
+    let mut count = 0;
+
+    loop {
+        let start = state.get_index();
 
-    while parser_succeeds {
         match parser().parse(state) {
             Ok(success) => {
-                results.push(success.get_result());
-
-                if let Err(failure) = separator().parse(state) {
-                    if failure.is_fatal() {
-                        return Err(failure);
-                    }
-                    parser_succeeds = false;
-                }
+                acc = f(acc, success.get_result());
+                count += 1;
             },
             Err(failure) => {
                 if failure.is_fatal() {
                     return Err(failure);
                 }
-                parser_succeeds = false;
+
+                break;
             },
         }
+
+        if let Err(failure) = separator().parse(state) {
+            if failure.is_fatal() {
+                return Err(failure);
+            }
+
+            if placement == Separator::After {
+                return Err(failure.to_fatal_err());
+            }
+
+            break;
+        }
+
+        if state.get_index() == start {
+            return Err(no_progress_failure(name, state));
+        }
     }
 
-    Ok(results)
-}
\ No newline at end of file
+    Ok((acc, count))
+}
+
+fn apply_parser_1<T, U>(name: &str, parser: fn() -> Parser<T>, separator: fn() -> Parser<U>, placement: Separator, state: &mut ParserState) -> Result<Vec<T>, ParserFailure> {
+    let results = apply_parser(name, parser, separator, placement, state)?;
+
+    if results.is_empty() {
+        Err(ParserFailure::new_err(
+            "value satisfying parser at least once".to_string(),
+            None,
+            state.get_position()
+        ))
+    } else {
+        Ok(results)
+    }
+}
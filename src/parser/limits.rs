@@ -0,0 +1,156 @@
+use std::time::{Duration, Instant};
+
+use super::result::{Position, ParserFailure};
+
+/// `ParserLimits` describes the limits placed on a parser applied by `run_with_limits`. Each limit is optional, and a parser that exceeds
+/// any of them fails with a `ParserFailure` of the `LimitExceeded` severity, which no other parser is able to recover from.
+///
+/// * `max_steps` is the number of parsers, including every parser nested in a combinator, that may be applied.
+/// * `max_depth` is the number of parsers that may be nested inside each other at any one time, which protects deeply recursive grammars from overflowing the stack.
+/// * `max_backtrack` is the number of bytes the parser state may be moved back in a single step by `attempt`, the `try_*` combinators, and other
+///   parsers able to revert the parser state.
+/// * `timeout` is the wall time the parser may take to finish.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// # use std::time::Duration;
+/// #
+/// let limits = ParserLimits::new()
+///     .with_max_steps(10_000)
+///     .with_max_depth(200)
+///     .with_max_backtrack(1024)
+///     .with_timeout(Duration::from_millis(50));
+///
+/// let expected = Ok(ParserSuccess::new(
+///     vec![1, 2, 3],
+///     Position::new(1, 6, 5)
+/// ));
+///
+/// let actual = sep_by(p_u32, || p_char(','))
+///     .run_with_limits(String::from("1,2,3"), limits);
+///
+/// assert_eq!(actual, expected);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ParserLimits {
+    max_steps: Option<usize>,
+    max_depth: Option<usize>,
+    max_backtrack: Option<usize>,
+    timeout: Option<Duration>,
+}
+
+impl ParserLimits {
+    /// `new` creates a new instance of the `ParserLimits` struct without any limits.
+    pub fn new() -> ParserLimits {
+        ParserLimits::default()
+    }
+
+    /// `with_max_steps` limits the number of parsers that may be applied.
+    pub fn with_max_steps(self, max_steps: usize) -> ParserLimits {
+        ParserLimits { max_steps: Some(max_steps), ..self }
+    }
+
+    /// `with_max_depth` limits the number of parsers that may be nested inside each other at any one time.
+    pub fn with_max_depth(self, max_depth: usize) -> ParserLimits {
+        ParserLimits { max_depth: Some(max_depth), ..self }
+    }
+
+    /// `with_max_backtrack` limits the number of bytes the parser state may be moved back at once.
+    pub fn with_max_backtrack(self, max_backtrack: usize) -> ParserLimits {
+        ParserLimits { max_backtrack: Some(max_backtrack), ..self }
+    }
+
+    /// `with_timeout` limits the wall time the parser may take to finish.
+    pub fn with_timeout(self, timeout: Duration) -> ParserLimits {
+        ParserLimits { timeout: Some(timeout), ..self }
+    }
+}
+
+/// `LimitTracker` is used by the parser state to keep track of the steps, depth and time used by the parser while limits are in place.
+/// Once a limit has been exceeded the failure is kept, so that every parser applied afterwards fails with it.
+pub(in crate::parser) struct LimitTracker {
+    limits: ParserLimits,
+    deadline: Option<Instant>,
+    steps: usize,
+    depth: usize,
+    exceeded: Option<ParserFailure>,
+}
+
+impl LimitTracker {
+    pub(in crate::parser) fn new(limits: ParserLimits) -> LimitTracker {
+        LimitTracker {
+            limits,
+            deadline: limits.timeout.map(|timeout| Instant::now() + timeout),
+            steps: 0,
+            depth: 0,
+            exceeded: None,
+        }
+    }
+
+    pub(in crate::parser) fn enter(&mut self, position: Position) -> Result<(), ParserFailure> {
+        self.steps += 1;
+        self.depth += 1;
+
+        if self.exceeded.is_none() {
+            self.exceeded = self.check_enter(position);
+        }
+
+        match &self.exceeded {
+            Some(failure) => Err(failure.clone()),
+            None => Ok(()),
+        }
+    }
+
+    pub(in crate::parser) fn leave(&mut self) -> Option<ParserFailure> {
+        self.depth = self.depth.saturating_sub(1);
+        self.exceeded.clone()
+    }
+
+    pub(in crate::parser) fn revert(&mut self, bytes: usize, position: Position) {
+        match self.limits.max_backtrack {
+            Some(max_backtrack) if bytes > max_backtrack && self.exceeded.is_none() => {
+                self.exceeded = Some(ParserFailure::new_limit_err(
+                    format!("backtracking of at most {} bytes", max_backtrack),
+                    Some(format!("backtracking of {} bytes", bytes)),
+                    position
+                ));
+            },
+            _ => (),
+        }
+    }
+
+    fn check_enter(&self, position: Position) -> Option<ParserFailure> {
+        if let Some(max_steps) = self.limits.max_steps {
+            if self.steps > max_steps {
+                return Some(ParserFailure::new_limit_err(
+                    format!("at most {} parser steps", max_steps),
+                    Some(format!("{} parser steps", self.steps)),
+                    position
+                ))
+            }
+        }
+
+        if let Some(max_depth) = self.limits.max_depth {
+            if self.depth > max_depth {
+                return Some(ParserFailure::new_limit_err(
+                    format!("parsers nested at most {} deep", max_depth),
+                    Some(format!("parsers nested {} deep", self.depth)),
+                    position
+                ))
+            }
+        }
+
+        match (self.deadline, self.limits.timeout) {
+            (Some(deadline), Some(timeout)) if Instant::now() >= deadline => {
+                Some(ParserFailure::new_limit_err(
+                    format!("parsing to finish within {:?}", timeout),
+                    None,
+                    position
+                ))
+            },
+            _ => None,
+        }
+    }
+}
@@ -2,9 +2,22 @@ pub mod result;
 pub mod char_parsers;
 pub mod combinators;
 pub mod state;
+pub mod trace;
+pub mod profile;
+pub mod limits;
+mod ops;
+mod trie;
 
 pub use state::ParserState;
-pub use result::{Position, ParserSuccess, ParserFailure, ParserResult, FailureSeverity};
+pub use result::{Position, Span, ParserSuccess, ParserFailure, ParserResult, FailureSeverity};
+pub use trace::{ParserTrace, TraceEntry, TraceOutcome};
+pub use profile::{ParserProfile, ProfileEntry};
+pub use limits::ParserLimits;
+
+use std::rc::Rc;
+
+use either::Either;
+use state::{MemoEntry, MemoId};
 
 /// `ParserFn` is a type alias for the closure returned by all parser functions and combinators. It takes a mutable reference
 /// to a `ParserState` struct and returns a `ParserResult` which can either be a `ParserSuccess` or a `ParserFailure`.
@@ -25,9 +38,17 @@ impl<T> Parser<T> {
     }
 
     /// `parse` is the method used to apply the parser function to a mutable reference of the `ParserState`.
+    /// If limits have been placed on the parser state and one of them is exceeded, the `LimitExceeded` failure is returned instead.
     pub(in crate::parser) fn parse(self, state: &mut ParserState) -> ParserResult<T> {
+        state.enter_parser()?;
+
         let p =self.parser_fn;
-        p(state)
+        let result = p(state);
+
+        match state.leave_parser() {
+            Some(failure) => Err(failure),
+            None => result,
+        }
     }
 
     /// `and` applies the parser contained in the current parser struct, and if it succeeds, it then applies the parser assigned to the `other` parameter.
@@ -125,6 +146,8 @@ impl<T> Parser<T> {
         let parser_fn =
             Box::new(
                 move |state: &mut ParserState| {
+                    let cuts = state.get_cuts();
+
                     state.mark();
 
                     let left = match self.parse(state) {
@@ -137,16 +160,12 @@ impl<T> Parser<T> {
 
                     let result = match other.parse(state) {
                         Ok(right) => {
+                            state.reset_cuts(cuts);
+
                             let result = (left.get_result(), right.get_result());
                             Ok(ParserSuccess::new(result, state.get_position()))
                         },
-                        Err(failure) => {
-                            if !failure.is_fatal() {
-                                state.revert();
-                            }
-
-                            Err(failure)
-                        },
+                        Err(failure) => revert_unless_cut(failure, cuts, state),
                     };
                       
                     state.remove_mark();
@@ -215,6 +234,49 @@ impl<T> Parser<T> {
         Parser::new(parser_fn)
     }
 
+    /// `or_either` works like `or`, except that the `other` parser may return a value of a different type. If the first parser succeeds
+    /// its result value is returned as `Either::Left`, and if the second parser succeeds its result value is returned as `Either::Right`.
+    /// 
+    /// # Errors
+    /// `or_either` will return a `ParserFailure` if both parsers fail. If the first parser fails after changing the parser state, 
+    /// the `FatalError` is returned without applying the second parser.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusty_parsec::*;
+    /// 
+    /// let expected = Ok(ParserSuccess::new(
+    ///     Either::Right(123), 
+    ///     Position::new(1, 4, 3)
+    /// ));
+    /// 
+    /// let actual = p_char('A')
+    ///     .or_either(p_u32())
+    ///     .run(String::from("123"));
+    /// 
+    /// assert_eq!(expected, actual);
+    /// ```
+    pub fn or_either<U>(self, other: Parser<U>) -> Parser<Either<T, U>>
+    {
+        let parser_fn =
+            Box::new(
+                move |state: &mut ParserState|
+                    match self.parse(state) {
+                        Ok(success) => Ok(success.map_result(Either::Left)),
+                        Err(failure) => {
+                            if failure.is_fatal() {
+                                Err(failure)
+                            } else {
+                                other.parse(state).map(|success| success.map_result(Either::Right))
+                            }
+                        },
+                    }
+            );
+
+        Parser::new(parser_fn)
+    }
+
     /// `take_prev` applies the parser contained in the current parser struct, and if it succeeds, it then applies the parser assigned to the `other` parameter.
     /// If both parsers succeed, the results of the first parser are returned as a `ParserSuccess` struct. If the first parser fails 
     /// without changing the parser state, a `ParserFailure` will be returned as an `Error`. If the first parser fails after changing the parser state
@@ -308,6 +370,8 @@ impl<T> Parser<T> {
         let parser_fn =
             Box::new(
                 move |state: &mut ParserState| {
+                    let cuts = state.get_cuts();
+
                     state.mark();
 
                     let prev = match self.parse(state) {
@@ -320,15 +384,10 @@ impl<T> Parser<T> {
 
                     let result = match other.parse(state) {
                         Ok(success) => {
+                            state.reset_cuts(cuts);
                             Ok(prev.with_position(success.get_position()))
                         },
-                        Err(failure) => {
-                            if !failure.is_fatal() {
-                                state.revert();
-                            }
-
-                            Err(failure)
-                        },
+                        Err(failure) => revert_unless_cut(failure, cuts, state),
                     };
                     
                     state.remove_mark();
@@ -430,19 +489,18 @@ impl<T> Parser<T> {
         let parser_fn =
             Box::new(
                 move |state: &mut ParserState| {
+                    let cuts = state.get_cuts();
+
                     state.mark();
 
                     let result = match self.parse(state) {
                         Ok(_) => {
                             match other.parse(state) {
-                                Ok(success) => Ok(success),
-                                Err(failure) => {
-                                    if !failure.is_fatal() {
-                                        state.revert();
-                                    }
-
-                                    Err(failure)
+                                Ok(success) => {
+                                    state.reset_cuts(cuts);
+                                    Ok(success)
                                 },
+                                Err(failure) => revert_unless_cut(failure, cuts, state),
                             }
                         },
                         Err(failure) => Err(failure),
@@ -463,7 +521,7 @@ impl<T> Parser<T> {
             Box::new(
                 move |state: &mut ParserState| {
                     let result = self.parse(state)?;
-                    Ok(ParserSuccess::new(return_value, result.get_position()))
+                    Ok(result.with_result(return_value))
                 }
             );
 
@@ -502,19 +560,19 @@ impl<T> Parser<T> {
         let parser_fn =
             Box::new(
                 move |state: &mut ParserState| {
+                    let cuts = state.get_cuts();
+
                     state.mark();
 
                     let result = match self.parse(state) {
                         Ok(success) => {
-                            f(success.get_result()).parse(state)
-                                .map_err(
-                                    |failure| {
-                                        if !failure.is_fatal() {
-                                            state.revert();                                    
-                                        }
-                                        failure
-                                    }
-                                )
+                            match f(success.get_result()).parse(state) {
+                                Ok(success) => {
+                                    state.reset_cuts(cuts);
+                                    Ok(success)
+                                },
+                                Err(failure) => revert_unless_cut(failure, cuts, state),
+                            }
                         },
                         Err(failure) => Err(failure),
                     };
@@ -574,9 +632,8 @@ impl<T> Parser<T> {
         let parser_fn =
             Box::new(
                 move |state: &mut ParserState| {
-                    match self.parse(state) {
-                        _ => Ok(ParserSuccess::new((), state.get_position())),
-                    }
+                    let _ = self.parse(state);
+                    Ok(ParserSuccess::new((), state.get_position()))
                 }
             );
 
@@ -660,7 +717,372 @@ impl<T> Parser<T> {
         Parser::new(parser_fn)
     }
 
+    /// `with_span` applies the parser and, if it succeeds, returns its result together with the `Span` of the input it consumed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusty_parsec::*;
+    /// 
+    /// let expected = Ok(ParserSuccess::new(
+    ///     (123, Span::new(Position::new(1, 3, 2), Position::new(1, 6, 5))), 
+    ///     Position::new(1, 6, 5)
+    /// ));
+    /// 
+    /// let actual = ws().take_next(p_u32().with_span())
+    ///     .run(String::from("  123"));
+    /// 
+    /// assert_eq!(expected, actual);
+    /// ```
+    pub fn with_span(self) -> Parser<(T, Span)> {
+        let parser_fn =
+            Box::new(
+                move |state: &mut ParserState| {
+                    let start = state.get_position();
+                    let result = self.parse(state)?;
+                    let span = Span::new(start, result.get_position());
+
+                    Ok(result.map_result(|value| (value, span)))
+                }
+            );
+
+        Parser::new(parser_fn)
+    }
+
+    /// `recognize` applies the parser and, if it succeeds, discards its result and returns the exact input text it consumed instead.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusty_parsec::*;
+    /// 
+    /// let expected = Ok(ParserSuccess::new(
+    ///     String::from("1.50"), 
+    ///     Position::new(1, 5, 4)
+    /// ));
+    /// 
+    /// let actual = p_f64().recognize()
+    ///     .run(String::from("1.50"));
+    /// 
+    /// assert_eq!(expected, actual);
+    /// ```
+    pub fn recognize(self) -> Parser<String> {
+        let parser_fn =
+            Box::new(
+                move |state: &mut ParserState| {
+                    let start = state.get_index();
+                    let result = self.parse(state)?;
+
+                    Ok(result.with_result(state.get_slice_since(start)))
+                }
+            );
+
+        Parser::new(parser_fn)
+    }
+
+    /// `with_recognized` applies the parser and, if it succeeds, returns its result together with the exact input text it consumed.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusty_parsec::*;
+    /// 
+    /// let expected = Ok(ParserSuccess::new(
+    ///     (1.5, String::from("1.50")), 
+    ///     Position::new(1, 5, 4)
+    /// ));
+    /// 
+    /// let actual = p_f64().with_recognized()
+    ///     .run(String::from("1.50"));
+    /// 
+    /// assert_eq!(expected, actual);
+    /// ```
+    pub fn with_recognized(self) -> Parser<(T, String)> {
+        let parser_fn =
+            Box::new(
+                move |state: &mut ParserState| {
+                    let start = state.get_index();
+                    let result = self.parse(state)?;
+                    let recognized = state.get_slice_since(start);
+
+                    Ok(ParserSuccess::new((result.get_result(), recognized), state.get_position()))
+                }
+            );
+
+        Parser::new(parser_fn)
+    }
+
+    /// `memoize` caches the result of the parser, whether it is a success or a failure, along with the position of the parser state after the parser
+    /// was applied. If a parser memoized with the same `id` is applied again at the same index of the input, for example after `attempt` or `choice`
+    /// has backtracked, the cached result is returned and the parser state is moved to the cached position without applying the parser again. 
+    /// This keeps the time spent parsing linear for grammars that backtrack heavily.
+    /// 
+    /// Memoized parsers that return the same type share cached results when they are given the same `id`, so every distinct parser must be given its own `id`.
+    /// Functions that build different parsers based on their arguments should include those arguments in the `id`, for example `format!("keyword {}", s)`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusty_parsec::*;
+    /// 
+    /// fn p_number() -> Parser<u32> {
+    ///     p_u32().memoize("number")
+    /// }
+    /// 
+    /// let expected = Ok(ParserSuccess::new(
+    ///     3, 
+    ///     Position::new(1, 4, 3)
+    /// ));
+    /// 
+    /// let actual = choice(vec![
+    ///     attempt(p_number().take_prev(p_char('+')).and(p_number()).map(Box::new(|(x, y)| x + y))),
+    ///     attempt(p_number().take_prev(p_char('-')).and(p_number()).map(Box::new(|(x, y)| x - y))),
+    ///     p_number(),
+    /// ]).run(String::from("5-2"));
+    /// 
+    /// assert_eq!(expected, actual);
+    /// ```
+    pub fn memoize(self, id: &str) -> Parser<T>
+    where T: Clone
+    {
+        let id = MemoId::Parser(Rc::from(id));
+
+        let parser_fn =
+            Box::new(
+                move |state: &mut ParserState| {
+                    let start = state.get_index();
+
+                    if let Some(entry) = state.get_memo::<T>(&id, start) {
+                        let (result, end, cuts) = (entry.result.clone(), entry.end, entry.cuts);
+
+                        if end > start {
+                            state.move_state_forward(end - start)?;
+                        }
+
+                        state.reset_cuts(state.get_cuts() + cuts);
+
+                        return result
+                    }
+
+                    let cuts = state.get_cuts();
+                    let result = self.parse(state);
+
+                    let entry = MemoEntry {
+                        result: result.clone(),
+                        end: state.get_index(),
+                        cuts: state.get_cuts().saturating_sub(cuts),
+                    };
+
+                    state.set_memo(&id, start, entry);
+                    result
+                }
+            );
+
+        Parser::new(parser_fn)
+    }
+
+    /// `trace` names the parser so that it is recorded when the parser is applied by `run_traced`. The name, the position where the parser was applied,
+    /// its outcome, and the position after it finished are recorded as a `TraceEntry`, along with the entries of any named parsers it applies.
+    /// The applications of the parser are also counted and timed when the parser is applied by `run_profiled`. When the parser is applied by `run`, 
+    /// `trace` has no effect.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusty_parsec::*;
+    /// 
+    /// let (result, trace) = p_u32().trace("number")
+    ///     .run_traced(String::from("x"));
+    /// 
+    /// let entry = &trace.get_entries()[0];
+    /// 
+    /// assert!(result.is_err());
+    /// assert_eq!(entry.name, "number");
+    /// assert_eq!(entry.outcome, TraceOutcome::Error);
+    /// assert_eq!(entry.end, Position::new(1, 1, 0));
+    /// ```
+    pub fn trace(self, name: &str) -> Parser<T> {
+        let name = name.to_string();
+
+        let parser_fn =
+            Box::new(
+                move |state: &mut ParserState| {
+                    state.enter_named_parser(&name);
+
+                    let result = self.parse(state);
+                    state.leave_named_parser(&result);
+
+                    result
+                }
+            );
+
+        Parser::new(parser_fn)
+    }
+
     pub fn run(self, input: String) -> ParserResult<T> {
         self.parse(&mut ParserState::new(input))
     }
+
+    /// `run_with_limits` works exactly like `run`, except that the `limits` are placed on the parser state. If the parser exceeds any of the limits,
+    /// parsing stops and a `ParserFailure` with the `LimitExceeded` severity is returned, describing the limit that was exceeded.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusty_parsec::*;
+    /// 
+    /// let expected = Err(ParserFailure::new_limit_err(
+    ///     String::from("at most 5 parser steps"), 
+    ///     Some(String::from("6 parser steps")),
+    ///     Position::new(1, 5, 4)
+    /// ));
+    /// 
+    /// let actual = many(|| p_char('a'))
+    ///     .run_with_limits(String::from("aaaaaaaa"), ParserLimits::new().with_max_steps(5));
+    /// 
+    /// assert_eq!(expected, actual);
+    /// ```
+    pub fn run_with_limits(self, input: String, limits: ParserLimits) -> ParserResult<T> {
+        let mut state = ParserState::new(input);
+        state.set_limits(limits);
+
+        self.parse(&mut state)
+    }
+
+    /// `run_traced` works exactly like `run`, except that the parser state is put into debug mode, and the `ParserTrace` recorded for the parsers
+    /// named with `trace` is returned along with the `ParserResult`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusty_parsec::*;
+    /// 
+    /// let (result, trace) = p_char('a').trace("a")
+    ///     .or(p_char('b').trace("b"))
+    ///     .run_traced(String::from("b"));
+    /// 
+    /// assert_eq!(result, Ok(ParserSuccess::new('b', Position::new(1, 2, 1))));
+    /// assert_eq!(trace.get_entries().len(), 2);
+    /// assert_eq!(trace.get_entries()[1].outcome, TraceOutcome::Success);
+    /// ```
+    pub fn run_traced(self, input: String) -> (ParserResult<T>, ParserTrace) {
+        self.parse_traced(ParserState::new(input))
+    }
+
+    /// `run_traced_with_limits` works exactly like `run_traced`, except that the `limits` are placed on the parser state as they are by `run_with_limits`.
+    /// A named parser that fails because a limit was exceeded is recorded with the `LimitExceeded` outcome.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusty_parsec::*;
+    /// 
+    /// let (result, trace) = many(|| p_char('a').trace("a"))
+    ///     .run_traced_with_limits(String::from("aaaa"), ParserLimits::new().with_max_steps(6));
+    /// 
+    /// let outcomes: Vec<TraceOutcome> = trace.get_entries()
+    ///     .iter()
+    ///     .map(|entry| entry.outcome)
+    ///     .collect();
+    /// 
+    /// assert!(result.unwrap_err().is_limit_exceeded());
+    /// assert_eq!(outcomes, vec![TraceOutcome::Success, TraceOutcome::Success, TraceOutcome::LimitExceeded]);
+    /// ```
+    pub fn run_traced_with_limits(self, input: String, limits: ParserLimits) -> (ParserResult<T>, ParserTrace) {
+        let mut state = ParserState::new(input);
+        state.set_limits(limits);
+
+        self.parse_traced(state)
+    }
+
+    fn parse_traced(self, mut state: ParserState) -> (ParserResult<T>, ParserTrace) {
+        state.enable_trace();
+
+        let result = self.parse(&mut state);
+        let trace = 
+            state.take_trace_recorder()
+                .map(|recorder| recorder.into_trace())
+                .unwrap_or_default();
+
+        (result, trace)
+    }
+
+    /// `run_profiled` works exactly like `run`, except that the applications of every parser named with `trace` are counted and timed, and the
+    /// `ParserProfile` report is returned along with the `ParserResult`. The report shows how often each named parser was applied, how often it
+    /// succeeded or failed, the bytes it consumed, the bytes that were re-scanned after the parser state was reverted while it was being applied, 
+    /// and the wall time spent applying it.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusty_parsec::*;
+    /// 
+    /// let p_keyword = attempt(p_string(String::from("let")).and(p_char(' '))).trace("keyword");
+    /// let p_name = many_satisfy(Box::new(|c: char| c.is_alphanumeric())).trace("name");
+    /// 
+    /// let (result, profile) = p_keyword.then_return(String::new())
+    ///     .or(p_name)
+    ///     .run_profiled(String::from("letter"));
+    /// 
+    /// let keyword = profile.get_entry("keyword").unwrap();
+    /// 
+    /// assert_eq!(result, Ok(ParserSuccess::new(String::from("letter"), Position::new(1, 7, 6))));
+    /// assert_eq!(keyword.failures, 1);
+    /// assert_eq!(keyword.bytes_rescanned, 3);
+    /// assert_eq!(profile.get_entry("name").unwrap().bytes_consumed, 6);
+    /// ```
+    pub fn run_profiled(self, input: String) -> (ParserResult<T>, ParserProfile) {
+        self.parse_profiled(ParserState::new(input))
+    }
+
+    /// `run_profiled_with_limits` works exactly like `run_profiled`, except that the `limits` are placed on the parser state as they are by `run_with_limits`.
+    /// The failures of a named parser caused by exceeding a limit are counted in the `limits_exceeded` of its `ProfileEntry`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusty_parsec::*;
+    /// 
+    /// let (_, profile) = many(|| p_char('a').trace("a"))
+    ///     .run_profiled_with_limits(String::from("aaaa"), ParserLimits::new().with_max_steps(6));
+    /// 
+    /// let entry = profile.get_entry("a").unwrap();
+    /// 
+    /// assert_eq!(entry.failures, 1);
+    /// assert_eq!(entry.limits_exceeded, 1);
+    /// ```
+    pub fn run_profiled_with_limits(self, input: String, limits: ParserLimits) -> (ParserResult<T>, ParserProfile) {
+        let mut state = ParserState::new(input);
+        state.set_limits(limits);
+
+        self.parse_profiled(state)
+    }
+
+    fn parse_profiled(self, mut state: ParserState) -> (ParserResult<T>, ParserProfile) {
+        state.enable_profile();
+
+        let result = self.parse(&mut state);
+        let profile = 
+            state.take_profile_recorder()
+                .map(|recorder| recorder.into_profile())
+                .unwrap_or_default();
+
+        (result, profile)
+    }
+}
+
+/// `revert_unless_cut` is used by the `try_*` combinators when the second parser fails. If a `cut` was made since the combinator was applied, 
+/// the failure is returned as a `FatalError` without reverting the parser state, and the cut is discarded so that it does not commit any enclosing parsers.
+/// Otherwise the parser state is reverted if the failure is an `Error`.
+fn revert_unless_cut<T>(failure: ParserFailure, cuts: usize, state: &mut ParserState) -> ParserResult<T> {
+    if state.get_cuts() > cuts {
+        state.reset_cuts(cuts);
+        return Err(failure.to_fatal_err())
+    }
+
+    if !failure.is_fatal() {
+        state.revert();
+    }
+
+    Err(failure)
 }
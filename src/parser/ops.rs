@@ -0,0 +1,156 @@
+use std::ops::{BitOr, Add, Shr, Shl, Sub, Not};
+
+use super::Parser;
+use super::combinators::not_followed_by;
+
+/// `|` is shorthand for `or`. The parser on the right side is only applied if the parser on the left side fails without changing the parser state.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     'B',
+///     Position::new(1, 2, 1)
+/// ));
+///
+/// let actual = (p_char('A') | p_char('B'))
+///     .run(String::from("B"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+impl<T> BitOr for Parser<T> {
+    type Output = Parser<T>;
+
+    fn bitor(self, other: Parser<T>) -> Parser<T> {
+        self.or(other)
+    }
+}
+
+/// `+` is shorthand for `and`. The result values of both parsers are returned as a tuple.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     ('A', 123),
+///     Position::new(1, 5, 4)
+/// ));
+///
+/// let actual = (p_char('A') + p_u32())
+///     .run(String::from("A123"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+impl<T, U> Add<Parser<U>> for Parser<T> {
+    type Output = Parser<(T, U)>;
+
+    fn add(self, other: Parser<U>) -> Parser<(T, U)> {
+        self.and(other)
+    }
+}
+
+/// `>>` is shorthand for `take_next`. Only the result value of the parser on the right side is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     123,
+///     Position::new(1, 5, 4)
+/// ));
+///
+/// let actual = (p_char('A') >> p_u32())
+///     .run(String::from("A123"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+impl<T, U> Shr<Parser<U>> for Parser<T> {
+    type Output = Parser<U>;
+
+    fn shr(self, other: Parser<U>) -> Parser<U> {
+        self.take_next(other)
+    }
+}
+
+/// `<<` is shorthand for `take_prev`. Only the result value of the parser on the left side is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     'A',
+///     Position::new(1, 5, 4)
+/// ));
+///
+/// let actual = (p_char('A') << p_u32())
+///     .run(String::from("A123"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+impl<T, U> Shl<Parser<U>> for Parser<T> {
+    type Output = Parser<T>;
+
+    fn shl(self, other: Parser<U>) -> Parser<T> {
+        self.take_prev(other)
+    }
+}
+
+/// `-` is shorthand for the `not_followed_by` method. The parser on the left side succeeds only if the parser on the right side
+/// fails immediately after it, and the input consumed by the parser on the right side is always restored.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Err(ParserFailure::new_fatal_err(
+///     String::from("following parser to fail"),
+///     None,
+///     Position::new(1, 3, 2)
+/// ));
+///
+/// let actual = (p_string(String::from("if")) - letter())
+///     .run(String::from("iffy"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+impl<T, U> Sub<Parser<U>> for Parser<T> {
+    type Output = Parser<T>;
+
+    fn sub(self, other: Parser<U>) -> Parser<T> {
+        self.not_followed_by(other)
+    }
+}
+
+/// `!` is shorthand for the `not_followed_by` function. It succeeds with `()` if the parser fails at the current position, without consuming any input.
+///
+/// # Examples
+///
+/// ```
+/// # use rusty_parsec::*;
+/// #
+/// let expected = Ok(ParserSuccess::new(
+///     String::from("if"),
+///     Position::new(1, 3, 2)
+/// ));
+///
+/// let actual = (p_string(String::from("if")) << !letter())
+///     .run(String::from("if x"));
+///
+/// assert_eq!(actual, expected);
+/// ```
+impl<T> Not for Parser<T> {
+    type Output = Parser<()>;
+
+    fn not(self) -> Parser<()> {
+        not_followed_by(self)
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use super::result::ParserResult;
+
+/// `ProfileEntry` summarizes every application of the parsers named with `trace` under the same `name`. The `bytes_consumed` are the bytes
+/// of input the parser moved the parser state forward, and the `bytes_rescanned` are the bytes the parser state was moved back by `attempt`,
+/// the `try_*` combinators and other parsers able to revert the parser state, while the named parser was being applied. The `time` is the wall time
+/// spent applying the parser, including the time spent in any nested parsers. The `limits_exceeded` are the failures, also counted in `failures`,
+/// that were caused by exceeding one of the `ParserLimits`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProfileEntry {
+    pub name: String,
+    pub invocations: usize,
+    pub successes: usize,
+    pub failures: usize,
+    pub limits_exceeded: usize,
+    pub bytes_consumed: usize,
+    pub bytes_rescanned: usize,
+    pub time: Duration,
+}
+
+/// `ParserProfile` is the report returned by `run_profiled` and `run_profiled_with_limits`, with a `ProfileEntry` for each name given to a parser with `trace`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParserProfile {
+    entries: BTreeMap<String, ProfileEntry>,
+}
+
+impl ParserProfile {
+    /// `get_entries` returns the entries of the report, ordered by name.
+    pub fn get_entries(&self) -> Vec<&ProfileEntry> {
+        self.entries.values().collect()
+    }
+
+    /// `get_entry` returns the entry for the parsers named `name`, or `None` if no parser with that name was applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rusty_parsec::*;
+    /// #
+    /// let (_, profile) = many(|| p_char('a').trace("a"))
+    ///     .run_profiled(String::from("aab"));
+    ///
+    /// let entry = profile.get_entry("a").unwrap();
+    ///
+    /// assert_eq!(entry.invocations, 3);
+    /// assert_eq!(entry.successes, 2);
+    /// assert_eq!(entry.failures, 1);
+    /// assert_eq!(entry.bytes_consumed, 2);
+    /// ```
+    pub fn get_entry(&self, name: &str) -> Option<&ProfileEntry> {
+        self.entries.get(name)
+    }
+}
+
+impl fmt::Display for ParserProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<24} {:>11} {:>9} {:>8} {:>6} {:>8} {:>9} {:>12}", "name", "invocations", "successes", "failures", "limits", "consumed", "rescanned", "time")?;
+
+        for entry in self.entries.values() {
+            writeln!(
+                f,
+                "{:<24} {:>11} {:>9} {:>8} {:>6} {:>8} {:>9} {:>12}",
+                entry.name,
+                entry.invocations,
+                entry.successes,
+                entry.failures,
+                entry.limits_exceeded,
+                entry.bytes_consumed,
+                entry.bytes_rescanned,
+                format!("{:?}", entry.time)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+struct OpenEntry {
+    name: String,
+    start: usize,
+    started_at: Instant,
+    bytes_rescanned: usize,
+}
+
+/// `ProfileRecorder` is used by the parser state while profiling is enabled. The named parsers that are being applied are kept on a stack, so that
+/// bytes re-scanned after the parser state is reverted can be added to each of them.
+#[derive(Default)]
+pub(in crate::parser) struct ProfileRecorder {
+    open: Vec<OpenEntry>,
+    profile: ParserProfile,
+}
+
+impl ProfileRecorder {
+    pub(in crate::parser) fn enter(&mut self, name: &str, start: usize) {
+        self.open.push(OpenEntry {
+            name: name.to_string(),
+            start,
+            started_at: Instant::now(),
+            bytes_rescanned: 0,
+        });
+    }
+
+    pub(in crate::parser) fn leave<T>(&mut self, result: &ParserResult<T>, end: usize) {
+        if let Some(open) = self.open.pop() {
+            let name = open.name.clone();
+            let entry =
+                self.profile.entries
+                    .entry(open.name)
+                    .or_insert_with(|| ProfileEntry { name, ..ProfileEntry::default() });
+
+            entry.invocations += 1;
+            entry.bytes_consumed += end.saturating_sub(open.start);
+            entry.bytes_rescanned += open.bytes_rescanned;
+            entry.time += open.started_at.elapsed();
+
+            match result {
+                Ok(_) => entry.successes += 1,
+                Err(failure) => {
+                    entry.failures += 1;
+
+                    if failure.is_limit_exceeded() {
+                        entry.limits_exceeded += 1;
+                    }
+                },
+            }
+        }
+    }
+
+    pub(in crate::parser) fn revert(&mut self, bytes: usize) {
+        for open in self.open.iter_mut() {
+            open.bytes_rescanned += bytes;
+        }
+    }
+
+    pub(in crate::parser) fn into_profile(self) -> ParserProfile {
+        self.profile
+    }
+}
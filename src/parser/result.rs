@@ -17,6 +17,36 @@ impl Position {
     pub fn new(line: usize, column: usize, index: usize) -> Position {
         Position { line, column, index }
     }
+
+    /// `get_line` returns the line number of the position, starting from 1.
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+
+    /// `get_column` returns the column number of the position, starting from 1.
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+
+    /// `get_index` returns the index of the position in the input string.
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+}
+
+/// `Span` describes the section of the input string consumed by a parser, from the `start` position where the parser was applied to the `end` position
+/// where the parser finished.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Span {
+    /// `new` creates a new instance of the `Span` struct.
+    pub fn new(start: Position, end: Position) -> Span {
+        Span { start, end }
+    }
 }
 
 pub mod success {
@@ -24,7 +54,7 @@ pub mod success {
 
     /// `ParserSuccess` is the type returned by a parser when it succeeds in parsing the input string. 
     /// When a parser succeeds both the value parsed and the current position of the parser state is returned.
-    #[derive(Debug, PartialEq)]
+    #[derive(Clone, Debug, PartialEq)]
     pub struct ParserSuccess<T> {
         result: T,
         position: Position,
@@ -104,10 +134,14 @@ pub mod failure {
     /// 
     /// However if the `ParserState` struct was changed by the first parser and a `Fatal` failure is returned, then the second 
     /// parser should not be attempted, because that would mean it is being applied at the incorrect index of the input string.
-    #[derive(Debug, PartialEq)]
+    /// 
+    /// `LimitExceeded` failures are returned when a parser applied by `run_with_limits` exceeds one of its `ParserLimits`. They are treated like a 
+    /// `FatalError` by every parser, but unlike a `FatalError` they are never changed to an `Error`, even by parsers that are able to revert the parser state.
+    #[derive(Clone, Debug, PartialEq)]
     pub enum FailureSeverity {
         Error,
-        FatalError
+        FatalError,
+        LimitExceeded,
     }
     
         /// `ParserFailure` is the type returned by a parser when it fails in parsing the input string. 
@@ -116,7 +150,7 @@ pub mod failure {
     /// 
     /// Optionally the `ParserFailure` struct will include the 
     /// string content that was parsed to aid in debugging, however not all parsers are able to provide this information.
-    #[derive(Debug, PartialEq)]
+    #[derive(Clone, Debug, PartialEq)]
     pub struct ParserFailure {
         expected: String,
         actual: Option<String>,
@@ -135,19 +169,35 @@ pub mod failure {
             ParserFailure { position, severity: FailureSeverity::FatalError, expected, actual, }
         }
     
+        /// `new_limit_err` creates a new instance of the `ParserFailure` struct with a failure severity of `LimitExceeded`.
+        pub fn new_limit_err(expected: String, actual: Option<String>, position: Position) -> ParserFailure {
+            ParserFailure { position, severity: FailureSeverity::LimitExceeded, expected, actual, }
+        }
+    
         /// `to_err` changes the `FailureSeverity` of a `ParserFailure` to the `Error` type. This is only used when
         /// a parser capable of rolling back the parser state encounters a fatal error but can recover the initial parser state before the failure.
         /// The `ParserFailure` returned by a parser with this capabality can safely return an `Error` type after reverting the parser state.
+        /// A `LimitExceeded` failure is returned unchanged.
+        #[allow(clippy::wrong_self_convention)]
         pub(in crate::parser) fn to_err(self) -> ParserFailure {
+            if self.is_limit_exceeded() {
+                return self
+            }
+
             ParserFailure::new_err(self.expected, self.actual, self.position)
         }
     
-        /// `to_fatal_err` changes the `FailureSeverity` of a `ParserFailure` to the `Fatal` type.
+        /// `to_fatal_err` changes the `FailureSeverity` of a `ParserFailure` to the `Fatal` type. A `LimitExceeded` failure is returned unchanged.
+        #[allow(clippy::wrong_self_convention)]
         pub(in crate::parser) fn to_fatal_err(self) -> ParserFailure {
+            if self.is_limit_exceeded() {
+                return self
+            }
+
             ParserFailure::new_fatal_err(self.expected, self.actual, self.position)
         }
     
-        /// `is_fatal` returns `true` if the `FailureSeverity` of a `ParserFailure` is a `FatalError`, otherwise it returns `false`.
+        /// `is_fatal` returns `true` if the `FailureSeverity` of a `ParserFailure` is a `FatalError` or `LimitExceeded`, otherwise it returns `false`.
         ///
         /// # Examples
         /// 
@@ -161,12 +211,28 @@ pub mod failure {
         /// assert!(!failure.is_fatal());
         /// ```
         pub fn is_fatal(&self) -> bool {
-            self.severity == FailureSeverity::FatalError
+            self.severity != FailureSeverity::Error
+        }
+
+        /// `is_limit_exceeded` returns `true` if the `FailureSeverity` of a `ParserFailure` is `LimitExceeded`, otherwise it returns `false`.
+        ///
+        /// # Examples
+        /// 
+        /// ```
+        /// use rusty_parsec::*;
+        /// 
+        /// let failure = ParserFailure::new_limit_err("at most 10 parser steps".to_string(), None, Position::new(1, 4, 3));
+        /// 
+        /// assert!(failure.is_limit_exceeded());
+        /// assert!(failure.is_fatal());
+        /// ```
+        pub fn is_limit_exceeded(&self) -> bool {
+            self.severity == FailureSeverity::LimitExceeded
         }
     
         /// `to_err_msg` takes a `ParserFailure` struct and returns the information it contains in a user friendly way.
         /// This method is primarily used for error messaging to help with debugging when a parser fails.
-        pub(in crate::parser) fn to_err_msg(&self) -> String {
+        pub fn to_err_msg(&self) -> String {
             match &self.actual {
                 Some(actual) => 
                     format!(
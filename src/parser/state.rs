@@ -1,4 +1,12 @@
-use super::result::Position;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use super::result::{Position, ParserResult, ParserFailure};
+use super::combinators::rule::RuleTable;
+use super::trace::TraceRecorder;
+use super::profile::ProfileRecorder;
+use super::limits::{ParserLimits, LimitTracker};
 
 #[derive(Clone, Debug, PartialEq)]
 enum LineStart {
@@ -6,6 +14,26 @@ enum LineStart {
     Index(usize),
 }
 
+/// ```MemoId``` identifies a parser in the memo table of the ```ParserState```, either by the id given to ```memoize``` or by the address of the function
+/// passed to ```rule```.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub(in crate::parser) enum MemoId {
+    Parser(Rc<str>),
+    Rule(usize),
+}
+
+/// ```MemoKey``` identifies an entry in the memo table of the ```ParserState```. An entry is keyed by the ```MemoId``` of the parser,
+/// the type of the value returned by the parser, and the index of the input where the parser was applied.
+type MemoKey = (MemoId, TypeId, usize);
+
+/// ```MemoEntry``` is the result of applying a memoized parser at an index of the input, together with the index of the parser state after it was applied
+/// and the number of cuts that were made while it was applied.
+pub(in crate::parser) struct MemoEntry<T> {
+    pub result: ParserResult<T>,
+    pub end: usize,
+    pub cuts: usize,
+}
+
 /// ```ParserState``` is used to track the state of the parser. It maintains a reference to the string value being parsed and the current position of the parser as well as a history of all previous positions. 
 /// ```ParserState``` also includes functionality for moving the current position of the parser forward and backward as well as tracking line and column numbers.
 pub struct ParserState {
@@ -15,6 +43,12 @@ pub struct ParserState {
     current_line_start: LineStart,
     prev_line_start: Vec<LineStart>,
     marker: Option<usize>,
+    cuts: usize,
+    memo: HashMap<MemoKey, Box<dyn Any>>,
+    rules: RuleTable,
+    trace: Option<TraceRecorder>,
+    profile: Option<ProfileRecorder>,
+    limits: Option<LimitTracker>,
 }
 
 impl ParserState {
@@ -28,6 +62,12 @@ impl ParserState {
             current_line_start: LineStart::FirstLine,
             prev_line_start: vec![],
             marker: None,
+            cuts: 0,
+            memo: HashMap::new(),
+            rules: RuleTable::default(),
+            trace: None,
+            profile: None,
+            limits: None,
         }
     }
 
@@ -37,34 +77,40 @@ impl ParserState {
     }
 
     /// ```get_remaining_input``` returns a slice of the input from the current position of the parser to the end of the input string. 
-    /// ```get_remaining_input``` panics if the current position of the parser has exceeded the length of the input.
     pub(in crate::parser) fn get_remaining_input(&self) -> &str {
-        if self.current_slice_start > self.len() {
-            panic!(
-                format!("starting slice at {} will exceed the input length of {}",
-                self.current_slice_start,
-                self.len())
-            )
-        }
-
-        &self.input[self.current_slice_start..]
+        self.input.get(self.current_slice_start..).unwrap_or_default()
     }
 
     /// ```move_state_forward``` moves the current position of the parser forward by the number of indicies specified with the ```increment``` parameter.
     /// When the position of the parser is moved, the characters between the current parser position and the new parser position are checked for newlines so that 
     /// the line number is tracked as well.
-    pub(in crate::parser) fn move_state_forward(&mut self, increment: usize) {
-        if self.current_slice_start + increment > self.len() {
-            panic!(
-                format!("incrementing starting index {} by {} will exceed the input length of {}",
-                self.current_slice_start, 
-                increment, 
-                self.len())
-            );
+    /// 
+    /// # Errors
+    /// ```move_state_forward``` returns an ```Error``` and leaves the parser state unchanged if the new parser position would exceed the length of the input
+    /// or fall inside of a multi-byte character.
+    pub(in crate::parser) fn move_state_forward(&mut self, increment: usize) -> Result<(), ParserFailure> {
+        let slice_end = self.current_slice_start.saturating_add(increment);
+
+        if slice_end > self.len() {
+            return Err(ParserFailure::new_err(
+                format!("{} more bytes of input", increment),
+                Some(format!("{} bytes", self.len() - self.current_slice_start)),
+                self.get_position()
+            ))
+        }
+
+        if !self.input.is_char_boundary(slice_end) {
+            return Err(ParserFailure::new_err(
+                format!("character boundary at index {}", slice_end),
+                Some("multi-byte character".to_string()),
+                self.get_position()
+            ))
         }
 
         self.move_newlines_forward(increment);
         self.move_slice_start_forward(increment);
+
+        Ok(())
     }
 
     fn move_slice_start_forward(&mut self, increment: usize) {
@@ -80,16 +126,32 @@ impl ParserState {
         let mut char_index = 0;
 
         for c in chars {
-            if c == '\n' {
-                self.prev_line_start.push(self.current_line_start.clone());
-                
-                self.current_line_start = LineStart::Index(self.current_slice_start + char_index);
+            let index = self.current_slice_start + char_index;
+
+            match c {
+                '\n' if self.is_carriage_return(index.wrapping_sub(1)) => {
+                    self.current_line_start = LineStart::Index(index);
+                },
+                '\n' | '\r' => {
+                    self.prev_line_start.push(self.current_line_start.clone());
+                    
+                    self.current_line_start = LineStart::Index(index);
+                },
+                _ => (),
             }
             
             char_index += c.len_utf8();
         }
     }
 
+    fn is_carriage_return(&self, index: usize) -> bool {
+        self.input.as_bytes().get(index) == Some(&b'\r')
+    }
+
+    fn is_line_feed(&self, index: usize) -> bool {
+        self.input.as_bytes().get(index) == Some(&b'\n')
+    }
+
     fn move_state_back(&mut self) {
         self.move_slice_start_back();
         self.move_newlines_back();
@@ -97,33 +159,30 @@ impl ParserState {
 
     fn move_slice_start_back(&mut self) {
         match self.prev_slice_start.pop() {
-            Some(0) if self.prev_slice_start.is_empty() => {
-                self.prev_slice_start = vec![0];
-                self.current_slice_start = 0;
-            },
-            Some(i) => {
+            Some(i) if !self.prev_slice_start.is_empty() => {
                 self.current_slice_start = i;
             },
-            None => {
-                panic!("slice start index cannot be moved back, vector of index history is empty")
+            _ => {
+                self.prev_slice_start = vec![0];
+                self.current_slice_start = 0;
             },
         }
     }
 
     fn move_newlines_back(&mut self) {
-        while ParserState::line_start_is_greater_than_slice_start(self.prev_line_start.last(), self.current_slice_start) {
-            self.prev_line_start.pop();
-        }
+        while let LineStart::Index(index) = self.current_line_start {
+            if index < self.current_slice_start {
+                break;
+            }
 
-        self.current_line_start = 
-            self.prev_line_start.pop()
-                .unwrap_or(LineStart::FirstLine);
-    }
+            if index == self.current_slice_start && self.is_line_feed(index) && self.is_carriage_return(index.wrapping_sub(1)) {
+                self.current_line_start = LineStart::Index(index - 1);
+                break;
+            }
 
-    fn line_start_is_greater_than_slice_start(line_start: Option<&LineStart>, slice_start: usize) -> bool {
-        match line_start {
-            Some(LineStart::Index(i)) => *i > slice_start,
-            _ => false,
+            self.current_line_start = 
+                self.prev_line_start.pop()
+                    .unwrap_or(LineStart::FirstLine);
         }
     }
 
@@ -135,14 +194,31 @@ impl ParserState {
 
     /// ```revert``` uses the marker set by ```mark``` to move the position of the parser to a previous state.
     pub(in crate::parser) fn revert(&mut self) {
-        match self.marker {
-            Some(marker) => {
-                while self.current_slice_start != marker {
-                    self.move_state_back();
-                }
-                self.remove_mark();
-            },
-            _ => ()
+        if let Some(marker) = self.marker {
+            self.revert_to(marker);
+            self.remove_mark();
+        }
+    }
+
+    /// ```revert_to``` moves the position of the parser back to the earlier index ```start``` without using or changing the marker set by ```mark```,
+    /// so that parsers which always restore the parser state are not affected by nested parsers that set their own markers.
+    pub(in crate::parser) fn revert_to(&mut self, start: usize) {
+        let bytes = self.current_slice_start.saturating_sub(start);
+
+        if let Some(profile) = self.profile.as_mut() {
+            profile.revert(bytes);
+        }
+
+        if self.limits.is_some() {
+            let position = self.get_position();
+
+            if let Some(limits) = self.limits.as_mut() {
+                limits.revert(bytes, position);
+            }
+        }
+
+        while self.current_slice_start > start {
+            self.move_state_back();
         }
     }
 
@@ -151,19 +227,121 @@ impl ParserState {
         self.marker = None;
     }
 
+    /// ```cut``` records a commit point made by the ```cut``` parser. Parsers that are able to revert the parser state compare the number of cuts
+    /// before and after applying a parser, and will not revert the parser state if a cut was made in between.
+    pub(in crate::parser) fn cut(&mut self) {
+        self.cuts += 1;
+    }
+
+    /// ```get_cuts``` returns the number of cuts that have been made and not yet reset.
+    pub(in crate::parser) fn get_cuts(&self) -> usize {
+        self.cuts
+    }
+
+    /// ```reset_cuts``` restores the number of cuts to an earlier value. This is used once a parser that can revert the parser state has succeeded,
+    /// so that the cuts made inside of it do not commit any enclosing parsers.
+    pub(in crate::parser) fn reset_cuts(&mut self, cuts: usize) {
+        self.cuts = cuts;
+    }
+
+    /// ```get_memo``` returns the memo table entry for the parser ```id``` if it has already been applied at the index ```start```.
+    pub(in crate::parser) fn get_memo<T: 'static>(&self, id: &MemoId, start: usize) -> Option<&MemoEntry<T>> {
+        self.memo.get(&(id.clone(), TypeId::of::<T>(), start))
+            .and_then(|entry| entry.downcast_ref::<MemoEntry<T>>())
+    }
+
+    /// ```set_memo``` stores the memo table entry for the parser ```id``` when it was applied at the index ```start```.
+    pub(in crate::parser) fn set_memo<T: 'static>(&mut self, id: &MemoId, start: usize, entry: MemoEntry<T>) {
+        self.memo.insert((id.clone(), TypeId::of::<T>(), start), Box::new(entry));
+    }
+
+    /// ```get_rule_table``` returns the table used by the ```rule``` parser to keep track of the rules that are being applied and of left recursion.
+    pub(in crate::parser) fn get_rule_table(&mut self) -> &mut RuleTable {
+        &mut self.rules
+    }
+
+    /// ```enable_trace``` turns on the debug mode of the parser state, in which every parser named with ```trace``` is recorded.
+    pub(in crate::parser) fn enable_trace(&mut self) {
+        self.trace = Some(TraceRecorder::default());
+    }
+
+    /// ```take_trace_recorder``` removes the recorder used in debug mode, turning debug mode off.
+    pub(in crate::parser) fn take_trace_recorder(&mut self) -> Option<TraceRecorder> {
+        self.trace.take()
+    }
+
+    /// ```enable_profile``` turns on profiling, in which the applications of every parser named with ```trace``` are counted and timed.
+    pub(in crate::parser) fn enable_profile(&mut self) {
+        self.profile = Some(ProfileRecorder::default());
+    }
+
+    /// ```take_profile_recorder``` removes the recorder used for profiling, turning profiling off.
+    pub(in crate::parser) fn take_profile_recorder(&mut self) -> Option<ProfileRecorder> {
+        self.profile.take()
+    }
+
+    /// ```enter_named_parser``` is called by ```trace``` before the named parser is applied, and records the current position if debug mode
+    /// or profiling is enabled.
+    pub(in crate::parser) fn enter_named_parser(&mut self, name: &str) {
+        let start = self.get_position();
+
+        if let Some(trace) = self.trace.as_mut() {
+            trace.enter(name, start);
+        }
+
+        if let Some(profile) = self.profile.as_mut() {
+            profile.enter(name, self.current_slice_start);
+        }
+    }
+
+    /// ```leave_named_parser``` is called by ```trace``` after the named parser is applied, and records the result of the parser if debug mode
+    /// or profiling is enabled.
+    pub(in crate::parser) fn leave_named_parser<T>(&mut self, result: &ParserResult<T>) {
+        let end = self.get_position();
+
+        if let Some(trace) = self.trace.as_mut() {
+            trace.leave(result, end);
+        }
+
+        if let Some(profile) = self.profile.as_mut() {
+            profile.leave(result, self.current_slice_start);
+        }
+    }
+
+    /// ```set_limits``` places the ```limits``` on every parser applied to the parser state.
+    pub(in crate::parser) fn set_limits(&mut self, limits: ParserLimits) {
+        self.limits = Some(LimitTracker::new(limits));
+    }
+
+    /// ```enter_parser``` is called before every parser is applied. It returns the ```LimitExceeded``` failure if the parser would exceed one of the
+    /// limits placed on the parser state, or if a limit has already been exceeded.
+    pub(in crate::parser) fn enter_parser(&mut self) -> Result<(), ParserFailure> {
+        if self.limits.is_none() {
+            return Ok(())
+        }
+
+        let position = self.get_position();
+
+        match self.limits.as_mut() {
+            Some(limits) => limits.enter(position),
+            None => Ok(()),
+        }
+    }
+
+    /// ```leave_parser``` is called after every parser is applied. It returns the ```LimitExceeded``` failure if a limit was exceeded while the parser
+    /// was being applied, so that it replaces the result of the parser.
+    pub(in crate::parser) fn leave_parser(&mut self) -> Option<ParserFailure> {
+        self.limits.as_mut().and_then(|limits| limits.leave())
+    }
+
     /// ```get_slice``` attempts to get a slice of the input to be evaluated by a parser function. The starting position of the slice
     /// is determined by the current position of the parser state and the end point of the slice is determined by the caller.
-    /// ```get_slice``` returns ```None``` if the slice requested exceeds the length of the input string.
+    /// ```get_slice``` returns ```None``` if the slice requested exceeds the length of the input string or ends inside of a multi-byte character.
     pub(in crate::parser) fn get_slice(&self, length: usize) -> Option<String> {
+        let slice_end = self.current_slice_start.checked_add(length)?;
 
-        let slice_end = self.current_slice_start + length;
-
-        if slice_end > self.len() {
-            None
-        } else {
-            let slice = &self.input[self.current_slice_start..slice_end];
-            Some(String::from(slice))
-        }
+        self.input.get(self.current_slice_start..slice_end)
+            .map(String::from)
     }
 
     /// ```get_position``` returns the current position of the parser state using the ```Position``` struct. 
@@ -172,7 +350,18 @@ impl ParserState {
         Position::new(self.get_line_number(), self.get_column_number(), self.get_index())
     }
 
-    fn get_index(&self) -> usize {
+    /// ```get_slice_since``` returns the input consumed by the parser between the index ```start``` and the current position of the parser.
+    pub(in crate::parser) fn get_slice_since(&self, start: usize) -> String {
+        self.get_slice_between(start, self.current_slice_start)
+    }
+
+    /// ```get_slice_between``` returns the input between the indices ```start``` and ```end```, which must both have been positions of the parser.
+    pub(in crate::parser) fn get_slice_between(&self, start: usize, end: usize) -> String {
+        String::from(self.input.get(start..end).unwrap_or_default())
+    }
+
+    /// ```get_index``` returns the index of the current position of the parser.
+    pub(in crate::parser) fn get_index(&self) -> usize {
         self.current_slice_start
     }
 
@@ -190,31 +379,31 @@ impl ParserState {
 
 #[cfg(test)]
 mod tests {
-    use super::{LineStart, ParserState};
+    use super::{LineStart, ParserState, ParserFailure, Position};
 
     #[test]
     fn gets_remaining_slice_of_input_to_be_parsed() {
         let mut state = ParserState::new("hello, world".to_string());
         
-        state.move_state_forward("hello".len());
+        state.move_state_forward("hello".len()).unwrap();
         let remaining_input = state.get_remaining_input();
 
         assert_eq!(", world", remaining_input);
     }
 
     #[test]
-    #[should_panic(expected = "will exceed the input length")]
-    fn get_remaining_slice_panics_if_slice_start_exceeds_input_length() {
+    fn get_remaining_slice_is_empty_if_slice_start_exceeds_input_length() {
         let mut state = ParserState::new("hello".to_string());
         state.current_slice_start = 7;
-        state.get_remaining_input();
+
+        assert_eq!("", state.get_remaining_input());
     }
 
     #[test]
     fn move_state_forward_increments_current_slice_start_by_one() {
         let mut state = ParserState::new("hello".to_string());
         
-        state.move_state_forward('h'.len_utf8());
+        state.move_state_forward('h'.len_utf8()).unwrap();
 
         assert_eq!('h'.len_utf8(), state.current_slice_start);
     }
@@ -223,7 +412,7 @@ mod tests {
     fn move_state_forward_increments_current_slice_start_by_many() {
         let mut state = ParserState::new("hello, world".to_string());
         
-        state.move_state_forward("hello".len());
+        state.move_state_forward("hello".len()).unwrap();
 
         assert_eq!("hello".len(), state.current_slice_start);
     }
@@ -232,7 +421,7 @@ mod tests {
     fn move_state_forward_increments_current_line_start() {
         let mut state = ParserState::new("hello\nworld".to_string());
         
-        state.move_state_forward("hello\nwo".len());
+        state.move_state_forward("hello\nwo".len()).unwrap();
 
         let expected = LineStart::Index(5);
 
@@ -243,7 +432,7 @@ mod tests {
     fn move_state_forward_does_not_increment_current_line_start() {
         let mut state = ParserState::new("hello\nworld".to_string());
         
-        state.move_state_forward("hello".len());
+        state.move_state_forward("hello".len()).unwrap();
 
         let expected = LineStart::FirstLine;
 
@@ -251,19 +440,52 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "will exceed the input length")]
-    fn move_state_forward_panics_if_increment_exceeds_input_length() {
+    fn move_state_forward_fails_if_increment_exceeds_input_length() {
         let mut state = ParserState::new("hello".to_string());
 
-        state.move_state_forward(7);
+        let expected = Err(ParserFailure::new_err(
+            "7 more bytes of input".to_string(),
+            Some("5 bytes".to_string()),
+            Position::new(1, 1, 0)
+        ));
+
+        assert_eq!(expected, state.move_state_forward(7));
+        assert_eq!(0, state.current_slice_start);
+    }
+
+    #[test]
+    fn move_state_forward_fails_if_increment_ends_inside_multi_byte_character() {
+        let mut state = ParserState::new("aé".to_string());
+
+        let expected = Err(ParserFailure::new_err(
+            "character boundary at index 2".to_string(),
+            Some("multi-byte character".to_string()),
+            Position::new(1, 1, 0)
+        ));
+
+        assert_eq!(expected, state.move_state_forward(2));
+        assert_eq!(0, state.current_slice_start);
+    }
+
+    #[test]
+    fn get_slice_returns_none_if_length_ends_inside_multi_byte_character() {
+        let mut state = ParserState::new("aéb".to_string());
+
+        assert_eq!(None, state.get_slice(2));
+        assert_eq!(Some("aé".to_string()), state.get_slice(3));
+
+        state.move_state_forward('a'.len_utf8()).unwrap();
+
+        assert_eq!(None, state.get_slice(1));
+        assert_eq!(Some("é".to_string()), state.get_slice(2));
     }
 
     #[test]
     fn move_state_back_sets_current_slice_start_back_one() {
         let mut state = ParserState::new("hello, world".to_string());
 
-        state.move_state_forward("hello".len());
-        state.move_state_forward(", ".len());
+        state.move_state_forward("hello".len()).unwrap();
+        state.move_state_forward(", ".len()).unwrap();
 
         assert_eq!(7, state.current_slice_start);
 
@@ -276,12 +498,24 @@ mod tests {
         assert_eq!(0, state.current_slice_start);
     }
 
+    #[test]
+    fn move_state_back_with_empty_history_stays_at_start_of_input() {
+        let mut state = ParserState::new("hello".to_string());
+        state.prev_slice_start.clear();
+
+        state.move_state_back();
+        state.move_state_back();
+
+        assert_eq!(0, state.current_slice_start);
+        assert_eq!(vec![0], state.prev_slice_start);
+    }
+
     #[test]
     fn move_state_back_sets_current_line_start_back_one() {
         let mut state = ParserState::new("hello\n, \nworld".to_string());
 
-        state.move_state_forward("hello\n,".len());
-        state.move_state_forward(" \nw".len());
+        state.move_state_forward("hello\n,".len()).unwrap();
+        state.move_state_forward(" \nw".len()).unwrap();
 
         assert_eq!(LineStart::Index(8), state.current_line_start);
 
@@ -294,16 +528,75 @@ mod tests {
         assert_eq!(LineStart::FirstLine, state.current_line_start);
     }
 
+    #[test]
+    fn move_state_forward_counts_carriage_return_line_feed_as_one_newline() {
+        let mut state = ParserState::new("hello\r\nworld".to_string());
+
+        state.move_state_forward("hello\r\nw".len()).unwrap();
+
+        assert_eq!(LineStart::Index(6), state.current_line_start);
+        assert_eq!(2, state.get_line_number());
+        assert_eq!(2, state.get_column_number());
+    }
+
+    #[test]
+    fn move_state_forward_counts_carriage_return_line_feed_split_across_moves_as_one_newline() {
+        let mut state = ParserState::new("hello\r\nworld".to_string());
+
+        state.move_state_forward("hello\r".len()).unwrap();
+        state.move_state_forward("\nw".len()).unwrap();
+
+        assert_eq!(LineStart::Index(6), state.current_line_start);
+        assert_eq!(2, state.get_line_number());
+        assert_eq!(2, state.get_column_number());
+    }
+
+    #[test]
+    fn move_state_forward_counts_lone_carriage_return_as_newline() {
+        let mut state = ParserState::new("a\rb\rc".to_string());
+
+        state.move_state_forward("a\rb\rc".len()).unwrap();
+
+        assert_eq!(LineStart::Index(3), state.current_line_start);
+        assert_eq!(3, state.get_line_number());
+        assert_eq!(2, state.get_column_number());
+    }
+
+    #[test]
+    fn move_state_back_between_carriage_return_and_line_feed_keeps_line() {
+        let mut state = ParserState::new("hello\r\nworld".to_string());
+
+        state.move_state_forward("hello\r".len()).unwrap();
+        state.move_state_forward("\nw".len()).unwrap();
+        state.move_state_back();
+
+        assert_eq!(LineStart::Index(5), state.current_line_start);
+        assert_eq!(2, state.get_line_number());
+        assert_eq!(1, state.get_column_number());
+    }
+
+    #[test]
+    fn move_state_back_within_line_keeps_line_start() {
+        let mut state = ParserState::new("a\nbc".to_string());
+
+        state.move_state_forward("a\n".len()).unwrap();
+        state.move_state_forward("b".len()).unwrap();
+        state.move_state_back();
+
+        assert_eq!(LineStart::Index(1), state.current_line_start);
+        assert_eq!(2, state.get_line_number());
+    }
+
     #[test]
     fn marks_current_slice_start_and_reverts_state_back_to_marker() {
         let mut state = ParserState::new("hello, world".to_string());
 
-        state.move_state_forward("hello".len());
+        state.move_state_forward("hello".len()).unwrap();
 
         state.mark();
 
-        state.move_state_forward(", ".len());
-        state.move_state_forward("world".len());
+        state.move_state_forward(", ".len()).unwrap();
+        state.move_state_forward("world".len()).unwrap();
 
         state.revert();
 
@@ -314,7 +607,7 @@ mod tests {
     fn calling_revert_with_no_change_in_state_does_not_affect_parser_state() {
         let mut state = ParserState::new("hello, world".to_string());
 
-        state.move_state_forward("hello".len());
+        state.move_state_forward("hello".len()).unwrap();
         state.mark();
         state.revert();
 
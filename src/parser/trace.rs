@@ -0,0 +1,229 @@
+use std::fmt;
+
+use super::result::{Position, ParserResult};
+
+/// `TraceOutcome` describes how a traced parser finished -- with a `ParserSuccess`, or with a `ParserFailure` of the `Error`, `FatalError`
+/// or `LimitExceeded` severity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TraceOutcome {
+    Success,
+    Error,
+    FatalError,
+    LimitExceeded,
+}
+
+impl TraceOutcome {
+    fn from_result<T>(result: &ParserResult<T>) -> TraceOutcome {
+        match result {
+            Ok(_) => TraceOutcome::Success,
+            Err(failure) if failure.is_limit_exceeded() => TraceOutcome::LimitExceeded,
+            Err(failure) if failure.is_fatal() => TraceOutcome::FatalError,
+            Err(_) => TraceOutcome::Error,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            TraceOutcome::Success => "Ok",
+            TraceOutcome::Error => "Error",
+            TraceOutcome::FatalError => "FatalError",
+            TraceOutcome::LimitExceeded => "LimitExceeded",
+        }
+    }
+}
+
+/// `TraceEntry` records a single application of a parser named with `trace`: the position where the parser was applied, how it finished,
+/// the position of the parser state after it finished, and the entries of the named parsers it applied in turn.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TraceEntry {
+    pub name: String,
+    pub start: Position,
+    pub outcome: TraceOutcome,
+    pub end: Position,
+    pub children: Vec<TraceEntry>,
+}
+
+/// `ParserTrace` is the tree of `TraceEntry` values recorded by `run_traced` and `run_traced_with_limits`, in the order the named parsers were applied.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ParserTrace {
+    entries: Vec<TraceEntry>,
+}
+
+impl ParserTrace {
+    /// `get_entries` returns the entries of the named parsers that were applied outside of any other named parser.
+    pub fn get_entries(&self) -> &[TraceEntry] {
+        &self.entries
+    }
+
+    /// `to_text` returns the trace as indented text, with a line for entering and a line for leaving each named parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rusty_parsec::*;
+    /// #
+    /// let (_, trace) = p_u32().trace("number")
+    ///     .and(p_char(';').trace("semicolon"))
+    ///     .trace("statement")
+    ///     .run_traced(String::from("12,"));
+    ///
+    /// let expected = "\
+    /// Entering statement: Ln: 1, Col: 1
+    ///   Entering number: Ln: 1, Col: 1
+    ///   Leaving number (Ok): Ln: 1, Col: 3
+    ///   Entering semicolon: Ln: 1, Col: 3
+    ///   Leaving semicolon (Error): Ln: 1, Col: 3
+    /// Leaving statement (FatalError): Ln: 1, Col: 3
+    /// ";
+    ///
+    /// assert_eq!(trace.to_text(), expected);
+    /// ```
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+
+        for entry in self.entries.iter() {
+            write_text(entry, 0, &mut text);
+        }
+
+        text
+    }
+
+    /// `to_json` returns the trace as a JSON array containing an object for each entry, with the `name`, `start`, `outcome`, `end` and `children` of the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rusty_parsec::*;
+    /// #
+    /// let (_, trace) = p_u32().trace("number")
+    ///     .run_traced(String::from("12"));
+    ///
+    /// let expected = concat!(
+    ///     r#"[{"name":"number","#,
+    ///     r#""start":{"line":1,"column":1,"index":0},"#,
+    ///     r#""outcome":"Ok","#,
+    ///     r#""end":{"line":1,"column":3,"index":2},"#,
+    ///     r#""children":[]}]"#
+    /// );
+    ///
+    /// assert_eq!(trace.to_json(), expected);
+    /// ```
+    pub fn to_json(&self) -> String {
+        write_json_entries(&self.entries)
+    }
+}
+
+impl fmt::Display for ParserTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
+
+/// `TraceRecorder` is used by the parser state while tracing is enabled. Entries are kept on a stack while their parsers are being applied,
+/// and are added to the entry of the enclosing named parser once they finish.
+#[derive(Default)]
+pub(in crate::parser) struct TraceRecorder {
+    open: Vec<TraceEntry>,
+    trace: ParserTrace,
+}
+
+impl TraceRecorder {
+    pub(in crate::parser) fn enter(&mut self, name: &str, start: Position) {
+        self.open.push(TraceEntry {
+            name: name.to_string(),
+            start,
+            outcome: TraceOutcome::Success,
+            end: start,
+            children: Vec::new(),
+        });
+    }
+
+    pub(in crate::parser) fn leave<T>(&mut self, result: &ParserResult<T>, end: Position) {
+        if let Some(mut entry) = self.open.pop() {
+            entry.outcome = TraceOutcome::from_result(result);
+            entry.end = end;
+
+            match self.open.last_mut() {
+                Some(parent) => parent.children.push(entry),
+                None => self.trace.entries.push(entry),
+            }
+        }
+    }
+
+    pub(in crate::parser) fn into_trace(self) -> ParserTrace {
+        self.trace
+    }
+}
+
+fn write_text(entry: &TraceEntry, depth: usize, text: &mut String) {
+    let indent = "  ".repeat(depth);
+
+    text.push_str(&format!(
+        "{}Entering {}: Ln: {}, Col: {}\n",
+        indent,
+        entry.name,
+        entry.start.get_line(),
+        entry.start.get_column()
+    ));
+
+    for child in entry.children.iter() {
+        write_text(child, depth + 1, text);
+    }
+
+    text.push_str(&format!(
+        "{}Leaving {} ({}): Ln: {}, Col: {}\n",
+        indent,
+        entry.name,
+        entry.outcome.as_str(),
+        entry.end.get_line(),
+        entry.end.get_column()
+    ));
+}
+
+fn write_json_entries(entries: &[TraceEntry]) -> String {
+    let entries: Vec<String> =
+        entries.iter()
+            .map(write_json_entry)
+            .collect();
+
+    format!("[{}]", entries.join(","))
+}
+
+fn write_json_entry(entry: &TraceEntry) -> String {
+    format!(
+        r#"{{"name":{},"start":{},"outcome":"{}","end":{},"children":{}}}"#,
+        write_json_string(&entry.name),
+        write_json_position(entry.start),
+        entry.outcome.as_str(),
+        write_json_position(entry.end),
+        write_json_entries(&entry.children)
+    )
+}
+
+fn write_json_position(position: Position) -> String {
+    format!(
+        r#"{{"line":{},"column":{},"index":{}}}"#,
+        position.get_line(),
+        position.get_column(),
+        position.get_index()
+    )
+}
+
+fn write_json_string(value: &str) -> String {
+    let mut json = String::from("\"");
+
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+struct Node {
+    children: HashMap<char, usize>,
+    value: Option<usize>,
+}
+
+impl Node {
+    fn new() -> Node {
+        Node { children: HashMap::new(), value: None }
+    }
+}
+
+/// `Trie` is a prefix tree used to match a set of strings against the input in a single pass. Each string in the tree is associated
+/// with an index, which callers use to look up the value belonging to that string.
+pub(in crate::parser) struct Trie {
+    nodes: Vec<Node>,
+}
+
+impl Trie {
+    /// `new` creates a `Trie` containing each of the `keys`, where every key is associated with its index in the slice.
+    /// If the same key appears more than once, the first occurrence is kept.
+    pub(in crate::parser) fn new(keys: &[&str]) -> Trie {
+        let mut trie = Trie { nodes: vec![Node::new()] };
+
+        for (index, key) in keys.iter().enumerate() {
+            trie.insert(key, index);
+        }
+
+        trie
+    }
+
+    fn insert(&mut self, key: &str, index: usize) {
+        let mut current = 0;
+
+        for c in key.chars() {
+            current = match self.nodes[current].children.get(&c) {
+                Some(&next) => next,
+                None => {
+                    self.nodes.push(Node::new());
+                    let next = self.nodes.len() - 1;
+                    self.nodes[current].children.insert(c, next);
+                    next
+                },
+            };
+        }
+
+        if self.nodes[current].value.is_none() {
+            self.nodes[current].value = Some(index);
+        }
+    }
+
+    /// `longest_match` walks the `input` through the tree and returns the byte length and index of the longest key that is a prefix
+    /// of the `input` and for which `accept` returns true when applied to the rest of the input following the key.
+    pub(in crate::parser) fn longest_match(&self, input: &str, accept: impl Fn(&str) -> bool) -> Option<(usize, usize)> {
+        let mut current = 0;
+        let mut length = 0;
+        let mut longest = self.accepted_match(current, input, length, &accept);
+
+        for c in input.chars() {
+            current = match self.nodes[current].children.get(&c) {
+                Some(&next) => next,
+                None => break,
+            };
+
+            length += c.len_utf8();
+            longest = self.accepted_match(current, input, length, &accept).or(longest);
+        }
+
+        longest
+    }
+
+    fn accepted_match(&self, node: usize, input: &str, length: usize, accept: &impl Fn(&str) -> bool) -> Option<(usize, usize)> {
+        match self.nodes[node].value {
+            Some(index) if accept(&input[length..]) => Some((length, index)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Trie;
+
+    #[test]
+    fn longest_match_prefers_longest_key() {
+        let trie = Trie::new(&["in", "interface", "int"]);
+
+        assert_eq!(Some((3, 2)), trie.longest_match("int x", |_| true));
+        assert_eq!(Some((9, 1)), trie.longest_match("interface", |_| true));
+        assert_eq!(Some((2, 0)), trie.longest_match("inner", |_| true));
+    }
+
+    #[test]
+    fn longest_match_returns_none_when_no_key_matches() {
+        let trie = Trie::new(&["in", "int"]);
+
+        assert_eq!(None, trie.longest_match("i", |_| true));
+        assert_eq!(None, trie.longest_match("", |_| true));
+    }
+
+    #[test]
+    fn longest_match_falls_back_to_shorter_accepted_key() {
+        let trie = Trie::new(&["in", "int"]);
+
+        assert_eq!(Some((2, 0)), trie.longest_match("int", |rest| !rest.is_empty()));
+    }
+
+    #[test]
+    fn duplicate_keys_keep_first_index() {
+        let trie = Trie::new(&["if", "if"]);
+
+        assert_eq!(Some((2, 0)), trie.longest_match("if", |_| true));
+    }
+}
@@ -80,6 +80,251 @@ fn many_satisfy_a_char_succeeds_when_no_values_returned_by_parser() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn many_satisfy_range_hex_digits_succeeds_stopping_at_max() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("ff00"), 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = many_satisfy_range(2, 4, Box::new(|c:char|c.is_ascii_hexdigit()))
+        .run(String::from("ff00cc"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_satisfy_range_hex_digits_fails_with_error_when_too_few_chars() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("at least 2 chars satisfying the condition"), 
+        Some(String::from("1 chars")),
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = many_satisfy_range(2, 4, Box::new(|c:char|c.is_ascii_hexdigit()))
+        .run(String::from("fg"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_satisfy_range_exact_count_fails_with_error() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("3 chars satisfying the condition"), 
+        Some(String::from("2 chars")),
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = many_satisfy_range(3, 3, Box::new(|c:char|c == 'é'))
+        .run(String::from("ééa"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn any_of_operator_chars_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        '-', 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = any_of("+-*/")
+        .run(String::from("-1"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn any_of_operator_chars_fails_with_error() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("any char in \"+-*/\""), 
+        Some(String::from("1")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = any_of("+-*/")
+        .run(String::from("1-"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn none_of_quote_chars_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        'a', 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = none_of("\"\\")
+        .run(String::from("a\""));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn none_of_quote_chars_fails_with_error_at_end_of_input() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("any char not in \"\\\"\\\\\""), 
+        None, 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = none_of("\"\\")
+        .run(String::new());
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_none_of_quote_chars_succeeds_with_multi_byte_chars() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("héllo"), 
+        Position::new(1, 7, 6)
+    ));
+
+    let actual = many_none_of("\"")
+        .run(String::from("héllo\""));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn char_range_lowercase_chars_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        'z', 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = char_range('a', 'z')
+        .run(String::from("z"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_1_char_range_fails_with_error() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("char in range '0'-'7'"), 
+        Some(String::from("8")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = many_1_char_range('0', '7')
+        .run(String::from("89"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn letter_fails_with_error() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("letter"), 
+        Some(String::from("1")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = letter()
+        .run(String::from("1a"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn digit_and_hex_digit_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        ('9', 'f'), 
+        Position::new(1, 3, 2)
+    ));
+
+    let actual = digit()
+        .and(hex_digit())
+        .run(String::from("9f"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_1_hex_digit_succeeds_with_hex_color() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("A0ff3c"), 
+        Position::new(1, 8, 7)
+    ));
+
+    let actual = p_char('#')
+        .take_next(many_1_hex_digit())
+        .run(String::from("#A0ff3c;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_alphanumeric_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("abc123"), 
+        Position::new(1, 7, 6)
+    ));
+
+    let actual = many_alphanumeric()
+        .run(String::from("abc123 def"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn upper_and_many_lower_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        ('H', String::from("ello")), 
+        Position::new(1, 6, 5)
+    ));
+
+    let actual = upper()
+        .and(many_lower())
+        .run(String::from("Hello World"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn upper_fails_with_error() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("uppercase letter"), 
+        Some(String::from("h")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = upper()
+        .run(String::from("hello"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn unicode_category_math_symbol_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        '∑', 
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = unicode_category(GeneralCategory::MathSymbol)
+        .run(String::from("∑x"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_1_unicode_category_fails_with_error() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("char in unicode category DecimalNumber"), 
+        Some(String::from("x")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = many_1_unicode_category(GeneralCategory::DecimalNumber)
+        .run(String::from("x1"));
+
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn p_string_hello_string_succeeds() {    
     let expected = Ok(ParserSuccess::new(
@@ -121,6 +366,193 @@ fn p_string_hello_string_fails_with_error_when_input_is_too_short() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn p_string_non_ascii_string_succeeds() {    
+    let expected = Ok(ParserSuccess::new(
+        String::from("héllo"), 
+        Position::new(1, 7, 6)
+    ));
+
+    let actual = p_string(String::from("héllo"))
+        .run(String::from("héllo, world"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn p_string_fails_with_error_when_slice_ends_inside_multi_byte_character() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("ab"), 
+        Some(String::from("aé")), 
+        Position::new(1, 1, 0)
+    ));
+        
+    let actual = p_string(String::from("ab"))
+        .run(String::from("aé"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn p_string_fails_with_error_when_slice_ends_inside_multi_byte_character_at_end_of_input() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("abc"), 
+        Some(String::from("a€")), 
+        Position::new(1, 1, 0)
+    ));
+        
+    let actual = p_string(String::from("abc"))
+        .run(String::from("a€"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn p_string_after_multi_byte_character_fails_with_fatal_error_inside_next_multi_byte_character() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("xy"), 
+        Some(String::from("日")), 
+        Position::new(1, 3, 2)
+    ));
+        
+    let actual = p_char('é')
+        .and(p_string(String::from("xy")))
+        .run(String::from("é日本"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn p_string_set_chooses_longest_match_regardless_of_order() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("interface"), 
+        Position::new(1, 10, 9)
+    ));
+
+    let actual = p_string_set(&["in", "interface", "int"])
+        .run(String::from("interface"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn p_string_set_matches_prefix_without_word_boundary() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("interface"), 
+        Position::new(1, 10, 9)
+    ));
+
+    let actual = p_string_set(&["in", "int", "interface"])
+        .run(String::from("interface1"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn p_string_set_fails_with_error() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("one of [\"in\", \"int\"]"), 
+        Some(String::from("if")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = p_string_set(&["in", "int"])
+        .run(String::from("if"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn string_set_return_operators_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        (3, 2), 
+        Position::new(1, 6, 5)
+    ));
+
+    let operators = || string_set_return(&[(">", 0), (">=", 1), (">>", 2), (">>=", 3)]);
+
+    let actual = operators()
+        .and(operators())
+        .run(String::from(">>=>>"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn p_keyword_set_requires_word_boundary() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("int"), 
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = p_keyword_set(&["in", "int", "interface"])
+        .run(String::from("int(x)"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn p_keyword_set_fails_with_error_when_followed_by_identifier_char() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("one of [\"in\", \"int\", \"interface\"]"), 
+        Some(String::from("interface")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = p_keyword_set(&["in", "int", "interface"])
+        .run(String::from("interface_1"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn keyword_set_return_falls_back_to_shorter_keyword_at_word_boundary() {
+    let expected = Ok(ParserSuccess::new(
+        1, 
+        Position::new(1, 3, 2)
+    ));
+
+    let actual = keyword_set_return(&[("in", 1), ("int", 2)])
+        .run(String::from("in t"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn p_string_set_fails_with_error_at_end_of_input() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("one of [\"in\", \"int\"]"), 
+        None, 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = p_string_set(&["in", "int"])
+        .run(String::new());
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn string_set_parser_run_shared_set_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        (vec![2, 1, 3], true), 
+        Position::new(1, 10, 9)
+    ));
+
+    let operators = StringSet::new(&[("+", 1), ("++", 2), ("-", 3)]);
+    let keywords = StringSet::new(&[("true", true), ("false", false)]);
+
+    let actual = operators.parser()
+        .and(operators.parser())
+        .and(operators.parser())
+        .map(Box::new(|((x, y), z)| vec![x, y, z]))
+        .take_prev(p_char(' '))
+        .and(keywords.keyword_parser())
+        .run(String::from("+++- true"));
+
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn ws_run_complex_series_of_parsers_succeeds() {
     let expected = Ok(ParserSuccess::new(
@@ -299,4 +731,369 @@ fn p_f64_decimal_number_succeeds() {
         .run(String::from("340282500000000000000000000000000000000.12"));
 
     assert_eq!(actual, expected);
-}
\ No newline at end of file
+}
+
+#[test]
+fn newline_normalises_carriage_return_line_feed_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        vec!['\n', '\n', '\n'], 
+        Position::new(4, 1, 4)
+    ));
+
+    let actual = newline()
+        .and(newline())
+        .and(newline())
+        .map(Box::new(|((a, b), c)| vec![a, b, c]))
+        .run(String::from("\r\n\n\r"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn newline_after_backtracking_across_carriage_returns_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        'z', 
+        Position::new(3, 2, 4)
+    ));
+
+    let actual = p_char('a')
+        .take_next(newline())
+        .take_next(
+            attempt(newline().take_next(p_char('x')))
+                .or(newline().take_next(p_char('z')))
+        )
+        .run(String::from("a\r\rz"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn newline_after_backtracking_across_mixed_newlines_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        'z', 
+        Position::new(3, 2, 5)
+    ));
+
+    let actual = p_char('a')
+        .take_next(p_char('\r'))
+        .take_next(
+            attempt(p_char('\n').take_next(newline()).take_next(p_char('x')))
+                .or(p_char('\n').take_next(newline()).take_next(p_char('z')))
+        )
+        .run(String::from("a\r\n\rz"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn newline_fails_with_error() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("newline"), 
+        Some(String::from("a")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = newline()
+        .run(String::from("a\n"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn unicode_newline_paragraph_separator_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        '\n', 
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = unicode_newline()
+        .run(String::from("\u{2029}"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn newline_does_not_accept_unicode_line_separator() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("newline"), 
+        Some(String::from("\u{2028}")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = newline()
+        .run(String::from("\u{2028}"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn rest_of_line_without_skipping_newline_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        (String::from("first"), '\n'), 
+        Position::new(2, 1, 7)
+    ));
+
+    let actual = rest_of_line(false)
+        .and(newline())
+        .run(String::from("first\r\nsecond"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn rest_of_line_succeeds_at_end_of_input() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("last"), 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = rest_of_line(true)
+        .run(String::from("last"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn skip_rest_of_line_skipping_newline_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("second"), 
+        Position::new(2, 7, 12)
+    ));
+
+    let actual = skip_rest_of_line(true)
+        .take_next(rest_of_line(true))
+        .run(String::from("first\rsecond"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn eof_succeeds_at_end_of_input() {
+    let expected = Ok(ParserSuccess::new(
+        (), 
+        Position::new(1, 6, 5)
+    ));
+
+    let actual = p_string(String::from("hello"))
+        .take_next(eof())
+        .run(String::from("hello"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn eof_fails_with_error() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("end of input"), 
+        Some(String::from("h")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = eof()
+        .run(String::from("hello"));
+
+    assert_eq!(actual, expected);
+}
+
+fn c_like_ws() -> Parser<()> {
+    ws_with(WhitespaceConfig {
+        line_comment: "//",
+        block_comment: ("/*", "*/"),
+        nested: true,
+        unicode_whitespace: true,
+    })
+}
+
+#[test]
+fn ws_with_line_and_block_comments_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        ('a', 'b'), 
+        Position::new(2, 5, 24)
+    ));
+
+    let actual = c_like_ws()
+        .take_next(p_char('a'))
+        .take_prev(c_like_ws())
+        .and(p_char('b'))
+        .run(String::from("/* one */ a // two\r\n\u{3000}b"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn ws_with_nested_block_comments_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        'x', 
+        Position::new(1, 21, 20)
+    ));
+
+    let actual = c_like_ws()
+        .take_next(p_char('x'))
+        .run(String::from("/* a /* b */ c */  x"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn ws_with_unnested_block_comments_stops_at_first_close() {
+    let config = WhitespaceConfig {
+        block_comment: ("/*", "*/"),
+        ..WhitespaceConfig::default()
+    };
+
+    let expected = Ok(ParserSuccess::new(
+        'c', 
+        Position::new(1, 15, 14)
+    ));
+
+    let actual = ws_with(config)
+        .take_next(p_char('c'))
+        .run(String::from("/* a /* b */ c */"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn ws_with_ascii_whitespace_does_not_skip_unicode_whitespace() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("a"), 
+        Some(String::from("\u{3000}")), 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = ws_with(WhitespaceConfig::default())
+        .take_next(p_char('a'))
+        .run(String::from(" \u{3000}a"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn ws_with_unterminated_block_comment_fails_with_error_at_opening_position() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("\"*/\" to close block comment"), 
+        None, 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = c_like_ws()
+        .run(String::from("/* a /* b */ c"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn ws_with_unterminated_block_comment_fails_with_fatal_error_after_whitespace() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("\"*/\" to close block comment"), 
+        None, 
+        Position::new(2, 3, 3)
+    ));
+
+    let actual = c_like_ws()
+        .run(String::from("\n  /* never closed"));
+
+    assert_eq!(actual, expected);
+}
+
+fn keyword_options() -> IdentifierOptions {
+    IdentifierOptions {
+        reserved_words: vec!["if", "else", "ifdef"],
+        ..IdentifierOptions::default()
+    }
+}
+
+#[test]
+fn identifier_with_default_options_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("_über_1"), 
+        Position::new(1, 9, 8)
+    ));
+
+    let actual = identifier(IdentifierOptions::default())
+        .run(String::from("_über_1 = 2"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn identifier_fails_with_error_when_starting_with_digit() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("identifier"), 
+        Some(String::from("1")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = identifier(IdentifierOptions::default())
+        .run(String::from("1abc"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn identifier_does_not_reject_identifier_starting_with_keyword() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("iffy"), 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = identifier(keyword_options())
+        .run(String::from("iffy"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn identifier_fails_with_error_at_keyword_position() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("identifier"), 
+        Some(String::from("keyword 'else'")), 
+        Position::new(2, 1, 6)
+    ));
+
+    let actual = identifier(keyword_options())
+        .take_prev(ws())
+        .and(identifier(keyword_options()))
+        .run(String::from("value\nelse"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn identifier_with_custom_predicates_succeeds() {
+    let options = IdentifierOptions {
+        is_start: |c| c.is_ascii_lowercase(),
+        is_continue: |c| c.is_ascii_lowercase() || c == '-',
+        ..IdentifierOptions::default()
+    };
+
+    let expected = Ok(ParserSuccess::new(
+        String::from("kebab-case"), 
+        Position::new(1, 11, 10)
+    ));
+
+    let actual = identifier(options)
+        .run(String::from("kebab-case_x"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn identifier_with_normalization_succeeds() {
+    let options = IdentifierOptions {
+        normalize: true,
+        reserved_words: vec!["café"],
+        ..IdentifierOptions::default()
+    };
+
+    let expected = Err(ParserFailure::new_err(
+        String::from("identifier"), 
+        Some(String::from("keyword 'café'")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = identifier(options)
+        .run(String::from("cafe\u{0301}"));
+
+    assert_eq!(actual, expected);
+}
@@ -0,0 +1,114 @@
+use rusty_parsec::*;
+
+#[derive(Debug, PartialEq)]
+enum Value {
+    Number(u32),
+    Bool(bool),
+    Text(String),
+}
+
+fn p_bool() -> Parser<bool> {
+    p_string(String::from("true")).then_return(true)
+        .or(p_string(String::from("false")).then_return(false))
+}
+
+fn p_text() -> Parser<String> {
+    p_char('"')
+        .take_next(many_satisfy(Box::new(|c| c != '"')))
+        .take_prev(p_char('"'))
+}
+
+#[test]
+fn alt_run_simple_parsers_succeeds_with_constructors() {
+    let expected = Ok(ParserSuccess::new(
+        Value::Text(String::from("abc")), 
+        Position::new(1, 6, 5)
+    ));
+
+    let actual = alt!(
+        p_u32() => Value::Number,
+        p_bool() => Value::Bool,
+        p_text() => Value::Text
+    ).run(String::from("\"abc\""));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn alt_run_simple_parsers_succeeds_with_closures() {
+    let expected = Ok(ParserSuccess::new(
+        Value::Number(7), 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = alt!(
+        p_bool() => Value::Bool,
+        digit() => |c: char| Value::Number(c.to_digit(10).unwrap_or_default()),
+    ).run(String::from("78"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn alt_run_simple_parsers_succeeds_with_anonymous_enum() {
+    let expected = Ok(ParserSuccess::new(
+        Alt4::D(String::from("abc")), 
+        Position::new(1, 6, 5)
+    ));
+
+    let actual = alt!(
+        p_char('x'),
+        p_u32(),
+        p_bool(),
+        p_text()
+    ).run(String::from("\"abc\""));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn alt_run_simple_parsers_succeeds_with_eight_parsers() {
+    let expected = Ok(ParserSuccess::new(
+        Alt8::H('h'), 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = alt!(
+        p_char('a'), p_u32(), p_bool(), p_text(), p_char('e'), p_f32(), p_char('g'), p_char('h')
+    ).run(String::from("h"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn alt_run_simple_parsers_fails_with_error_at_last_parser() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("\""), 
+        Some(String::from("x")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = alt!(
+        p_u32() => Value::Number,
+        p_bool() => Value::Bool,
+        p_text() => Value::Text
+    ).run(String::from("x"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn alt_run_complex_parsers_fails_with_fatal_error_without_applying_remaining_parsers() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("\""), 
+        None, 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = alt!(
+        p_text(),
+        p_u32(),
+    ).run(String::from("\"123"));
+
+    assert_eq!(actual, expected);
+}
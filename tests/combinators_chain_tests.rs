@@ -0,0 +1,145 @@
+use rusty_parsec::*;
+
+fn p_minus() -> Parser<ChainFn<i32>> {
+    p_char('-').then_return(Box::new(|x, y| x - y))
+}
+
+fn p_plus() -> Parser<ChainFn<i32>> {
+    p_char('+')
+        .and(p_char('='))
+        .then_return(Box::new(|x, y| x + y))
+}
+
+#[test]
+fn chain_left_run_simple_parsers_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        -4,
+        Position::new(1, 6, 5)
+    ));
+
+    let actual = chain_left(p_i32, p_minus, 0)
+        .run(String::from("1-2-3"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn chain_left_run_simple_parsers_succeeds_with_default_when_parser_fails() {
+    let expected = Ok(ParserSuccess::new(
+        10,
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = chain_left(p_i32, p_minus, 10)
+        .run(String::from("a-2"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn chain_left_run_simple_parsers_fails_with_fatal_error_when_operand_is_missing() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("integral value"),
+        None,
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = chain_left(p_i32, p_minus, 0)
+        .run(String::from("1-2-"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn chain_left_run_simple_parsers_fails_with_fatal_error_when_operator_fails_fatally() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("="),
+        Some(String::from("2")),
+        Position::new(1, 3, 2)
+    ));
+
+    let actual = chain_left(p_i32, p_plus, 0)
+        .run(String::from("1+2"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn chain_left_1_run_simple_parsers_succeeds_with_single_value() {
+    let expected = Ok(ParserSuccess::new(
+        7,
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = chain_left_1(p_i32, p_minus)
+        .run(String::from("7;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn chain_left_1_run_simple_parsers_fails_with_error_when_parser_fails() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("integral value"),
+        None,
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = chain_left_1(p_i32, p_minus)
+        .run(String::from("-"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn chain_right_run_simple_parsers_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        2,
+        Position::new(1, 6, 5)
+    ));
+
+    let actual = chain_right(p_i32, p_minus, 0)
+        .run(String::from("1-2-3"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn chain_right_run_simple_parsers_succeeds_with_default_when_parser_fails() {
+    let expected = Ok(ParserSuccess::new(
+        10,
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = chain_right(p_i32, p_minus, 10)
+        .run(String::from(""));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn chain_right_1_run_simple_parsers_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        3,
+        Position::new(1, 6, 5)
+    ));
+
+    let actual = chain_right_1(p_i32, p_minus)
+        .run(String::from("4-2-1;2"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn chain_right_1_run_simple_parsers_fails_with_fatal_error_when_operand_is_missing() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("integral value"),
+        None,
+        Position::new(1, 3, 2)
+    ));
+
+    let actual = chain_right_1(p_i32, p_minus)
+        .run(String::from("4-;"));
+
+    assert_eq!(actual, expected);
+}
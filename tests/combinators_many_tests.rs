@@ -140,4 +140,234 @@ fn skip_many_1_run_simple_parsers_fails_with_error_when_no_values_returned_by_pa
         .run(String::from("abc"));
 
     assert_eq!(actual, expected);
-}
\ No newline at end of file
+}
+
+#[test]
+fn count_run_simple_parsers_succeeds_with_exact_number_of_values() {
+    let expected = Ok(ParserSuccess::new(
+        vec![1, 2], 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = count(2, || p_u32().take_prev(p_char(';')))
+        .run(String::from("1;2;3;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn count_run_simple_parsers_succeeds_when_n_is_zero() {
+    let expected = Ok(ParserSuccess::new(
+        Vec::new(), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = count(0, p_u32)
+        .run(String::from("1"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn count_run_simple_parsers_fails_with_fatal_error_when_too_few_values() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("3 values satisfying parser"), 
+        Some(String::from("2 values")),
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = count(3, || p_u32().take_prev(p_char(';')))
+        .run(String::from("1;2;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn count_run_simple_parsers_fails_with_error_when_no_values_returned_by_parser() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("3 values satisfying parser"), 
+        Some(String::from("0 values")),
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = count(3, p_u32)
+        .run(String::from("abc"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn count_run_complex_parsers_fails_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from(";"), 
+        Some(String::from(",")),
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = count(3, || p_u32().and(p_char(';')))
+        .run(String::from("1;2,3;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_range_run_simple_parsers_succeeds_stopping_at_max() {
+    let expected = Ok(ParserSuccess::new(
+        vec!['a', 'b', 'c'], 
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = many_range(1, 3, letter)
+        .run(String::from("abcd"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_range_run_simple_parsers_succeeds_between_min_and_max() {
+    let expected = Ok(ParserSuccess::new(
+        vec!['a', 'b'], 
+        Position::new(1, 3, 2)
+    ));
+
+    let actual = many_range(1, 3, letter)
+        .run(String::from("ab1"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_range_run_simple_parsers_fails_with_fatal_error_when_too_few_values() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("at least 3 values satisfying parser"), 
+        Some(String::from("2 values")),
+        Position::new(1, 3, 2)
+    ));
+
+    let actual = many_range(3, 5, letter)
+        .run(String::from("ab1"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn skip_count_run_simple_parsers_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        (), 
+        Position::new(1, 3, 2)
+    ));
+
+    let actual = skip_count(2, letter)
+        .run(String::from("abc"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_fold_run_simple_parsers_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        6, 
+        Position::new(1, 7, 6)
+    ));
+
+    let actual = many_fold(|| p_u32().take_prev(p_char(';')), 0, Box::new(|sum, n| sum + n))
+        .run(String::from("1;2;3;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_fold_run_simple_parsers_succeeds_with_init_when_no_values_returned_by_parser() {
+    let expected = Ok(ParserSuccess::new(
+        10, 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = many_fold(p_u32, 10, Box::new(|sum, n| sum + n))
+        .run(String::from("abc"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_fold_run_complex_parsers_fails_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from(";"), 
+        Some(String::from(",")),
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = many_fold(|| p_u32().take_prev(p_char(';')), 0, Box::new(|sum, n| sum + n))
+        .run(String::from("1;2,3;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_collect_run_simple_parsers_succeeds_collecting_into_string() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("abc"), 
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = many_collect::<char, String>(letter)
+        .run(String::from("abc1"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_run_non_consuming_parser_fails_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("parser applied by many to consume input"), 
+        Some(String::from("parser succeeded without consuming input")),
+        Position::new(1, 3, 2)
+    ));
+
+    let actual = many(|| p_char('a').opt())
+        .run(String::from("aab"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn skip_many_1_run_non_consuming_parser_fails_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("parser applied by skip_many_1 to consume input"), 
+        Some(String::from("parser succeeded without consuming input")),
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = skip_many_1(ws)
+        .run(String::from("abc"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn count_run_non_consuming_parser_fails_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("parser applied by count to consume input"), 
+        Some(String::from("parser succeeded without consuming input")),
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = count(3, || p_char('a').opt())
+        .run(String::from("ab"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_collect_run_non_consuming_parser_fails_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("parser applied by many_collect to consume input"), 
+        Some(String::from("parser succeeded without consuming input")),
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = many_collect::<String, Vec<String>>(|| many_satisfy(Box::new(|c| c.is_numeric())))
+        .run(String::from("abc"));
+
+    assert_eq!(actual, expected);
+}
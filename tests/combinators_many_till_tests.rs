@@ -191,4 +191,138 @@ fn skip_many_1_till_run_simple_parsers_fails_with_error_when_no_values_parsed_by
         .run(String::from("1234"));
 
     assert_eq!(actual, expected);
-}
\ No newline at end of file
+}
+
+#[test]
+fn many_till_with_end_run_simple_parsers_succeeds_with_end_value() {
+    let expected = Ok(ParserSuccess::new(
+        (vec![true, true, true], 123), 
+        Position::new(1, 16, 15)
+    ));
+
+    let actual = many_till_with_end(p_true, p_u32)
+        .run(String::from("truetruetrue123"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_till_with_end_run_simple_parsers_succeeds_when_no_values_returned_by_first_parser() {
+    let expected = Ok(ParserSuccess::new(
+        (Vec::new(), 123), 
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = many_till_with_end(p_true, p_u32)
+        .run(String::from("123"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_till_with_end_run_complex_parsers_succeeds_with_matching_closing_tag() {
+    let expected = Ok(ParserSuccess::new(
+        (vec![String::from("hello"), String::from("hello")], String::from("</b>")), 
+        Position::new(1, 15, 14)
+    ));
+
+    let actual = many_till_with_end(p_hello, || p_string(String::from("</a>")).or(p_string(String::from("</b>"))))
+        .run(String::from("hellohello</b>"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_1_till_with_end_run_simple_parsers_fails_with_error_when_no_values_returned_by_first_parser() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("true"), 
+        Some(String::from("1234")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = many_1_till_with_end(p_true, p_u32)
+        .run(String::from("1234"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_chars_till_run_simple_parsers_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("line one\nline two\n"), 
+        Position::new(3, 4, 21)
+    ));
+
+    let actual = many_chars_till(Box::new(|_| true), || p_string(String::from("EOF")))
+        .run(String::from("line one\nline two\nEOF"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_chars_till_run_simple_parsers_succeeds_with_multi_byte_characters() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("héllo wörld"), 
+        Position::new(1, 18, 17)
+    ));
+
+    let actual = many_chars_till(Box::new(|_| true), || p_string(String::from("</p>")))
+        .run(String::from("héllo wörld</p>"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_chars_till_run_simple_parsers_succeeds_when_no_chars_parsed() {
+    let expected = Ok(ParserSuccess::new(
+        String::new(), 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = many_chars_till(Box::new(|c: char| c.is_alphabetic()), || p_char(';'))
+        .run(String::from(";abc"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_chars_till_run_simple_parsers_fails_with_error_at_end_parser() {
+    let expected = Err(ParserFailure::new_err(
+        String::from(";"), 
+        Some(String::from("1")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = many_chars_till(Box::new(|c: char| c.is_alphabetic()), || p_char(';'))
+        .run(String::from("1abc;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_chars_till_run_simple_parsers_fails_with_fatal_error_at_end_of_input() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("EOF"), 
+        None, 
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = many_chars_till(Box::new(|_| true), || p_string(String::from("EOF")))
+        .run(String::from("abc"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn many_till_run_non_consuming_parser_fails_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("parser applied by many_till to consume input"), 
+        Some(String::from("parser succeeded without consuming input")),
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = many_till(|| p_char('a').opt(), || p_char(';'))
+        .run(String::from("ab;"));
+
+    assert_eq!(actual, expected);
+}
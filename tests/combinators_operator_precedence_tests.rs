@@ -0,0 +1,313 @@
+use std::cell::Cell;
+
+use rusty_parsec::*;
+
+thread_local! {
+    static OPERATOR_TABLES_BUILT: Cell<usize> = const { Cell::new(0) };
+}
+
+#[derive(Debug, PartialEq)]
+enum Expr {
+    Num(i32),
+    Neg(Box<Expr>),
+    Fact(Box<Expr>),
+    Bin(String, Box<Expr>, Box<Expr>),
+    Cond(Box<Expr>, Box<Expr>, Box<Expr>),
+}
+
+fn num(n: i32) -> Expr {
+    Expr::Num(n)
+}
+
+fn bin(op: &str, left: Expr, right: Expr) -> Expr {
+    Expr::Bin(op.to_string(), Box::new(left), Box::new(right))
+}
+
+fn infix(op: &'static str) -> Box<dyn Fn(Expr, Expr) -> Expr> {
+    Box::new(move |left, right| bin(op, left, right))
+}
+
+fn p_term(opp: &OperatorPrecedenceParser<Expr>) -> Parser<Expr> {
+    let p_num = p_i32().map(Box::new(Expr::Num));
+    let p_parens = opp.expression_parser().between(p_char('(').take_prev(ws()), p_char(')'));
+
+    p_num.or(p_parens).take_prev(ws())
+}
+
+fn opp() -> OperatorPrecedenceParser<Expr> {
+    OPERATOR_TABLES_BUILT.with(|built| built.set(built.get() + 1));
+
+    let opp = OperatorPrecedenceParser::new_recursive(p_term, ws);
+
+    opp.add_ternary_operator("?", ":", 1, Associativity::Right, Box::new(|c, t, f| Expr::Cond(Box::new(c), Box::new(t), Box::new(f))));
+    opp.add_infix_operator("==", 2, Associativity::None, infix("=="));
+    opp.add_infix_operator("+", 3, Associativity::Left, infix("+"));
+    opp.add_infix_operator("-", 3, Associativity::Left, infix("-"));
+    opp.add_infix_operator("++", 3, Associativity::Right, infix("++"));
+    opp.add_infix_operator("*", 4, Associativity::Left, infix("*"));
+    opp.add_prefix_operator("-", 5, Box::new(|x| Expr::Neg(Box::new(x))));
+    opp.add_infix_operator("^", 6, Associativity::Right, infix("^"));
+    opp.add_postfix_operator("!", 7, Box::new(|x| Expr::Fact(Box::new(x))));
+
+    opp
+}
+
+fn p_expr() -> Parser<Expr> {
+    opp().expression_parser()
+}
+
+#[test]
+fn operator_precedence_parser_left_associative_operators_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        bin("+", bin("-", num(1), num(2)), num(3)),
+        Position::new(1, 10, 9)
+    ));
+
+    let actual = p_expr()
+        .run(String::from("1 - 2 + 3"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_right_associative_operators_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        bin("^", num(2), bin("^", num(3), num(2))),
+        Position::new(1, 6, 5)
+    ));
+
+    let actual = p_expr()
+        .run(String::from("2^3^2"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_higher_precedence_binds_tighter_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        bin("+", num(1), bin("*", num(2), bin("^", num(3), num(4)))),
+        Position::new(1, 16, 15)
+    ));
+
+    let actual = p_expr()
+        .run(String::from("1 + 2 * 3 ^ 4  "));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_parenthesised_term_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        bin("*", bin("+", num(1), num(2)), num(3)),
+        Position::new(1, 12, 11)
+    ));
+
+    let actual = p_expr()
+        .run(String::from("(1 + 2) * 3"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_prefix_and_postfix_operators_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        bin("*", Expr::Neg(Box::new(bin("^", num(2), Expr::Fact(Box::new(num(3)))))), num(4)),
+        Position::new(1, 12, 11)
+    ));
+
+    let actual = p_expr()
+        .run(String::from("-2 ^ 3! * 4"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_ternary_operator_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        Expr::Cond(
+            Box::new(num(1)),
+            Box::new(bin("+", num(2), num(3))),
+            Box::new(Expr::Cond(Box::new(num(4)), Box::new(num(5)), Box::new(num(6))))
+        ),
+        Position::new(1, 22, 21)
+    ));
+
+    let actual = p_expr()
+        .run(String::from("1 ? 2 + 3 : 4 ? 5 : 6"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_ternary_operator_fails_with_fatal_error_when_missing_right_operator() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from(":"),
+        Some(String::from(";")),
+        Position::new(1, 7, 6)
+    ));
+
+    let actual = p_expr()
+        .run(String::from("1 ? 2 ;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_non_associative_operators_fails_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("operator conflicts with associativity"),
+        Some(String::from("==")),
+        Position::new(1, 8, 7)
+    ));
+
+    let actual = p_expr()
+        .run(String::from("1 == 2 == 3"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_mixed_associativity_fails_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("operator conflicts with associativity"),
+        Some(String::from("++")),
+        Position::new(1, 7, 6)
+    ));
+
+    let actual = p_expr()
+        .run(String::from("1 + 2 ++ 3"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_mixed_associativity_in_right_operand_fails_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("operator conflicts with associativity"),
+        Some(String::from("+")),
+        Position::new(1, 8, 7)
+    ));
+
+    let actual = p_expr()
+        .run(String::from("1 ++ 2 + 3"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_fails_with_error_when_term_fails() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("("),
+        Some(String::from("*")),
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = p_expr()
+        .run(String::from("* 1"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_fails_with_fatal_error_when_operand_is_missing() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("("),
+        None,
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = p_expr()
+        .run(String::from("1 + "));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_nested_parentheses_reuse_operator_table() {
+    let expected = Ok(ParserSuccess::new(
+        bin("*", bin("+", num(1), num(2)), bin("-", num(3), num(4))),
+        Position::new(1, 22, 21)
+    ));
+
+    OPERATOR_TABLES_BUILT.with(|built| built.set(0));
+
+    let actual = p_expr()
+        .run(String::from("((1 + 2)) * ((3 - 4))"));
+
+    assert_eq!(actual, expected);
+    assert_eq!(OPERATOR_TABLES_BUILT.with(|built| built.get()), 1);
+}
+
+fn p_int_term() -> Parser<i64> {
+    p_i64().take_prev(ws())
+}
+
+#[test]
+fn operator_precedence_parser_uses_operators_added_after_parser_is_created() {
+    let opp = OperatorPrecedenceParser::new(p_int_term, ws);
+    opp.add_infix_operator("+", 1, Associativity::Left, Box::new(|x, y| x + y));
+
+    let parser = opp.expression_parser();
+    let shared = opp.clone();
+    shared.add_infix_operator("*", 2, Associativity::Left, Box::new(|x, y| x * y));
+
+    let expected = Ok(ParserSuccess::new(
+        7,
+        Position::new(1, 10, 9)
+    ));
+
+    let actual = parser
+        .run(String::from("1 + 2 * 3"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_replaces_operator_with_same_string() {
+    let opp = OperatorPrecedenceParser::new(p_int_term, ws);
+    opp.add_infix_operator("-", 1, Associativity::Left, Box::new(|x, y| x - y));
+    opp.add_infix_operator("-", 1, Associativity::Right, Box::new(|x, y| x - y));
+
+    let expected = Ok(ParserSuccess::new(
+        2,
+        Position::new(1, 10, 9)
+    ));
+
+    let actual = opp.expression_parser()
+        .run(String::from("3 - 2 - 1"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_left_associative_operator_with_max_precedence_succeeds() {
+    let opp = OperatorPrecedenceParser::new(p_int_term, ws);
+    opp.add_infix_operator("-", u32::MAX, Associativity::Left, Box::new(|x, y| x - y));
+
+    let expected = Ok(ParserSuccess::new(
+        0,
+        Position::new(1, 10, 9)
+    ));
+
+    let actual = opp.expression_parser()
+        .run(String::from("3 - 2 - 1"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operator_precedence_parser_prefix_operator_with_max_precedence_succeeds() {
+    let opp = OperatorPrecedenceParser::new(p_int_term, ws);
+    opp.add_infix_operator("+", 1, Associativity::Left, Box::new(|x, y| x + y));
+    opp.add_prefix_operator("-", u32::MAX, Box::new(|x| -x));
+
+    let expected = Ok(ParserSuccess::new(
+        1,
+        Position::new(1, 7, 6)
+    ));
+
+    let actual = opp.expression_parser()
+        .run(String::from("-2 + 3"));
+
+    assert_eq!(actual, expected);
+}
@@ -0,0 +1,153 @@
+use rusty_parsec::*;
+
+fn p_width() -> Parser<u32> {
+    p_string(String::from("width=")).take_next(p_u32()).take_prev(ws())
+}
+
+fn p_height() -> Parser<u32> {
+    p_string(String::from("height=")).take_next(p_u32()).take_prev(ws())
+}
+
+fn p_title() -> Parser<String> {
+    p_string(String::from("title=")).take_next(many_1_letter()).take_prev(ws())
+}
+
+fn p_hidden() -> Parser<bool> {
+    p_string(String::from("hidden")).then_return(true).take_prev(ws())
+}
+
+#[test]
+fn permutation_2_run_simple_parsers_succeeds_in_declaration_order() {
+    let expected = Ok(ParserSuccess::new(
+        (10, 20), 
+        Position::new(1, 19, 18)
+    ));
+
+    let actual = permutation_2(
+        required_field(p_width),
+        required_field(p_height),
+        Box::new(|width, height| (width, height))
+    ).run(String::from("width=10 height=20"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn permutation_2_run_simple_parsers_succeeds_in_reverse_order() {
+    let expected = Ok(ParserSuccess::new(
+        (10, 20), 
+        Position::new(1, 19, 18)
+    ));
+
+    let actual = permutation_2(
+        required_field(p_width),
+        required_field(p_height),
+        Box::new(|width, height| (width, height))
+    ).run(String::from("height=20 width=10"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn permutation_4_run_simple_parsers_succeeds_with_missing_optional_fields() {
+    let expected = Ok(ParserSuccess::new(
+        (10, None, 20, Some(true)), 
+        Position::new(1, 26, 25)
+    ));
+
+    let actual = permutation_4(
+        required_field(p_width),
+        optional_field(p_title),
+        required_field(p_height),
+        optional_field(p_hidden),
+        Box::new(|width, title, height, hidden| (width, title, height, hidden))
+    ).run(String::from("hidden height=20 width=10"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn permutation_2_run_simple_parsers_fails_with_error_when_no_fields_parsed() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("width="), 
+        Some(String::from("depth=")),
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = permutation_2(
+        required_field(p_width),
+        optional_field(p_height),
+        Box::new(|width, height| (width, height))
+    ).run(String::from("depth=5"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn permutation_2_run_simple_parsers_succeeds_with_default_when_no_fields_parsed() {
+    let expected = Ok(ParserSuccess::new(
+        (None, None), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = permutation_2(
+        optional_field(p_width),
+        optional_field(p_height),
+        Box::new(|width, height| (width, height))
+    ).run(String::from("depth=5"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn permutation_3_run_simple_parsers_fails_with_fatal_error_when_required_field_missing() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("height="), 
+        None,
+        Position::new(1, 17, 16)
+    ));
+
+    let actual = permutation_3(
+        required_field(p_width),
+        required_field(p_height),
+        optional_field(p_title),
+        Box::new(|width, height, title| (width, height, title))
+    ).run(String::from("title=a width=10"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn permutation_3_run_simple_parsers_fails_with_fatal_error_on_duplicate_field() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("each permutation field at most once"), 
+        Some(String::from("width=30")),
+        Position::new(1, 20, 19)
+    ));
+
+    let actual = permutation_3(
+        required_field(p_width),
+        required_field(p_height),
+        optional_field(p_title),
+        Box::new(|width, height, title| (width, height, title))
+    ).run(String::from("width=10 height=20 width=30"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn permutation_2_run_complex_parsers_fails_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("integral value"), 
+        None,
+        Position::new(1, 16, 15)
+    ));
+
+    let actual = permutation_2(
+        required_field(p_width),
+        required_field(p_height),
+        Box::new(|width, height| (width, height))
+    ).run(String::from("width=1 height=x"));
+
+    assert_eq!(actual, expected);
+}
@@ -348,4 +348,134 @@ fn tuple_5_run_simple_parsers_fails_with_fatal_error_at_fifth_parser() {
     ).run(String::from("hello123true1.5c"));
 
     assert_eq!(actual, expected);
-}
\ No newline at end of file
+}
+
+fn p_digit_value() -> Parser<u32> {
+    digit().map(Box::new(|c| c.to_digit(10).unwrap_or_default()))
+}
+
+#[test]
+fn pipe_run_simple_parsers_succeeds_with_thirteen_values() {
+    let expected = Ok(ParserSuccess::new(
+        vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 2, 3], 
+        Position::new(1, 14, 13)
+    ));
+
+    let actual = pipe!(
+        p_digit_value(), p_digit_value(), p_digit_value(), p_digit_value(), p_digit_value(),
+        p_digit_value(), p_digit_value(), p_digit_value(), p_digit_value(), p_digit_value(),
+        p_digit_value(), p_digit_value(), p_digit_value()
+        => |a, b, c, d, e, f, g, h, i, j, k, l, m| vec![a, b, c, d, e, f, g, h, i, j, k, l, m]
+    ).run(String::from("1234567890123"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn seq_run_simple_parsers_succeeds_skipping_values() {
+    let expected = Ok(ParserSuccess::new(
+        (123,), 
+        Position::new(1, 7, 6)
+    ));
+
+    let actual = seq!(
+        _ = ws(), _ = p_char('['), p_u32(), _ = p_char(']'),
+    ).run(String::from(" [123]"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn seq_run_simple_parsers_fails_with_error_at_first_parser() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("["), 
+        Some(String::from("1")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = seq!(
+        _ = p_char('['), p_u32(), _ = p_char(']')
+    ).run(String::from("123]"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn seq_run_simple_parsers_fails_with_fatal_error_at_skipped_parser() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("]"), 
+        None, 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = seq!(
+        _ = p_char('['), p_u32(), _ = p_char(']')
+    ).run(String::from("[123"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn pipe_run_simple_parsers_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("hello 123 true"), 
+        Position::new(1, 18, 17)
+    ));
+
+    let actual = pipe!(
+        p_hello(), _ = ws(), p_u32(), _ = ws(), p_true()
+        => |hello, n, t| format!("{} {} {}", hello, n, t)
+    ).run(String::from("hello  123   true"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn pipe_run_simple_parsers_succeeds_with_twelve_values() {
+    let expected = Ok(ParserSuccess::new(
+        72, 
+        Position::new(1, 24, 23)
+    ));
+
+    let actual = pipe!(
+        p_digit_value(), _ = p_char(','), p_digit_value(), _ = p_char(','), p_digit_value(), _ = p_char(','),
+        p_digit_value(), _ = p_char(','), p_digit_value(), _ = p_char(','), p_digit_value(), _ = p_char(','),
+        p_digit_value(), _ = p_char(','), p_digit_value(), _ = p_char(','), p_digit_value(), _ = p_char(','),
+        p_digit_value(), _ = p_char(','), p_digit_value(), _ = p_char(','), p_digit_value()
+        => |a, b, c, d, e, f, g, h, i, j, k, l| a + b + c + d + e + f + g + h + i + j + k + l
+    ).run(String::from("1,2,3,4,5,6,7,8,9,9,9,9"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn pipe_run_complex_parsers_fails_with_fatal_error_at_first_parser() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("hello"), 
+        Some(String::from("hellx")), 
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = pipe!(
+        p_u32().and(p_hello()), _ = ws(), p_true()
+        => |(n, hello), t| (n, hello, t)
+    ).run(String::from("123hellx true"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn pipe_run_simple_parsers_fails_with_fatal_error_at_last_parser() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("true"), 
+        Some(String::from("fals")), 
+        Position::new(1, 11, 10)
+    ));
+
+    let actual = pipe!(
+        p_hello(), _ = ws(), p_u32(), _ = ws(), p_true()
+        => |hello, n, t| (hello, n, t)
+    ).run(String::from("hello 123 false"));
+
+    assert_eq!(actual, expected);
+}
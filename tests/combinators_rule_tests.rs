@@ -0,0 +1,124 @@
+use rusty_parsec::*;
+
+fn p_expr() -> Parser<i32> {
+    attempt(rule(p_expr).take_prev(p_char('-')).and(rule(p_term)))
+        .map(Box::new(|(x, y)| x - y))
+        .or(rule(p_term))
+}
+
+fn p_term() -> Parser<i32> {
+    attempt(rule(p_term).take_prev(p_char('/')).and(rule(p_factor)))
+        .map(Box::new(|(x, y)| x / y))
+        .or(rule(p_factor))
+}
+
+fn p_factor() -> Parser<i32> {
+    rule(p_expr)
+        .between(p_char('('), p_char(')'))
+        .or(p_i32())
+}
+
+fn p_a() -> Parser<String> {
+    attempt(rule(p_b).and(p_char('x')))
+        .map(Box::new(|(b, x)| format!("({}{})", b, x)))
+        .or(p_char('a').map(Box::new(|a| a.to_string())))
+}
+
+fn p_b() -> Parser<String> {
+    attempt(rule(p_a).and(p_char('y')))
+        .map(Box::new(|(a, y)| format!("({}{})", a, y)))
+        .or(p_char('b').map(Box::new(|b| b.to_string())))
+}
+
+#[test]
+fn rule_run_direct_left_recursion_succeeds_with_left_associative_result() {
+    let expected = Ok(ParserSuccess::new(
+        4, 
+        Position::new(1, 7, 6)
+    ));
+
+    let actual = rule(p_expr)
+        .run(String::from("10-4-2;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn rule_run_nested_left_recursion_succeeds_with_left_associative_result() {
+    let expected = Ok(ParserSuccess::new(
+        -3, 
+        Position::new(1, 14, 13)
+    ));
+
+    let actual = rule(p_expr)
+        .run(String::from("8/2/2-(9-4)/1"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn rule_run_indirect_left_recursion_succeeds_with_left_associative_result() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("(((ay)x)y)"), 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = rule(p_b)
+        .run(String::from("ayxy"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn rule_run_indirect_left_recursion_succeeds_starting_from_other_rule() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("(((bx)y)x)"), 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = rule(p_a)
+        .run(String::from("bxyx"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn rule_run_left_recursion_succeeds_with_seed_when_recursion_cannot_grow() {
+    let expected = Ok(ParserSuccess::new(
+        7, 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = rule(p_expr)
+        .run(String::from("7-"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn rule_run_left_recursion_fails_with_error() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("integral value"), 
+        None, 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = rule(p_expr)
+        .run(String::from("/1"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn rule_run_left_recursion_fails_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from(")"), 
+        Some(String::from(";")), 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = rule(p_factor)
+        .run(String::from("(2-3;"));
+
+    assert_eq!(actual, expected);
+}
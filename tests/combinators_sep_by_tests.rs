@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use rusty_parsec::*;
 
 #[test]
@@ -169,4 +170,266 @@ fn skip_sep_by_1_run_simple_parsers_fails_with_error_when_no_values_returned_by_
     ).run(String::from("a;b;c"));
 
     assert_eq!(actual, expected);
-}
\ No newline at end of file
+}
+
+#[test]
+fn sep_by_run_simple_parsers_succeeds_consuming_trailing_separator() {
+    let expected = Ok(ParserSuccess::new(
+        vec![1, 2, 3], 
+        Position::new(1, 7, 6)
+    ));
+
+    let actual = sep_by(
+        p_u32,
+        || p_char(';')
+    ).run(String::from("1;2;3;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn sep_by_run_simple_parsers_succeeds_when_separator_consumes_no_input() {
+    let expected = Ok(ParserSuccess::new(
+        vec![1, 2, 3], 
+        Position::new(1, 6, 5)
+    ));
+
+    let actual = sep_by(
+        p_u32,
+        ws
+    ).run(String::from("1 2 3"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn sep_by_run_complex_parsers_succeeds_when_optional_separator_consumes_no_input() {
+    let expected = Ok(ParserSuccess::new(
+        vec![1, 2], 
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = sep_by(
+        p_u32,
+        || p_char(',').opt()
+    ).run(String::from("1,2 x"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn sep_end_by_run_simple_parsers_succeeds_with_trailing_separator() {
+    let expected = Ok(ParserSuccess::new(
+        vec![1, 2, 3], 
+        Position::new(1, 7, 6)
+    ));
+
+    let actual = sep_end_by(
+        p_u32,
+        || p_char(';')
+    ).run(String::from("1;2;3;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn sep_end_by_run_simple_parsers_succeeds_without_trailing_separator() {
+    let expected = Ok(ParserSuccess::new(
+        vec![1, 2, 3], 
+        Position::new(1, 6, 5)
+    ));
+
+    let actual = sep_end_by(
+        p_u32,
+        || p_char(';')
+    ).run(String::from("1;2;3"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn sep_end_by_run_complex_parsers_fails_parsing_separator_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from(">"), 
+        Some(String::from("?")), 
+        Position::new(1, 6, 5)
+    ));
+
+    let actual = sep_end_by(
+        p_u32,
+        || p_char('<').and(p_char('>'))
+    ).run(String::from("1<>2<?"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn sep_end_by_1_run_simple_parsers_fails_with_error_when_no_values_returned_by_parser() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("value satisfying parser at least once"), 
+        None, 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = sep_end_by_1(
+        p_u32,
+        || p_char(';')
+    ).run(String::from(";"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn skip_sep_end_by_1_run_simple_parsers_succeeds_with_trailing_separator() {
+    let expected = Ok(ParserSuccess::new(
+        (), 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = skip_sep_end_by_1(
+        p_u32,
+        || p_char(';')
+    ).run(String::from("1;2;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn end_by_run_simple_parsers_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        vec![1, 2, 3], 
+        Position::new(1, 7, 6)
+    ));
+
+    let actual = end_by(
+        p_u32,
+        || p_char(';')
+    ).run(String::from("1;2;3;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn end_by_run_simple_parsers_succeeds_when_no_values_returned_by_parser() {
+    let expected = Ok(ParserSuccess::new(
+        Vec::new(), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = end_by(
+        p_u32,
+        || p_char(';')
+    ).run(String::from("a;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn end_by_run_simple_parsers_fails_with_fatal_error_when_separator_is_missing() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from(";"), 
+        Some(String::from("a")), 
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = end_by(
+        p_u32,
+        || p_char(';')
+    ).run(String::from("1;2a"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn end_by_1_run_simple_parsers_fails_with_error_when_no_values_returned_by_parser() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("value satisfying parser at least once"), 
+        None, 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = end_by_1(
+        p_u32,
+        || p_char(';')
+    ).run(String::from("a;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn skip_end_by_run_simple_parsers_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        (), 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = skip_end_by(
+        p_u32,
+        || p_char(';')
+    ).run(String::from("1;2;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn sep_by_fold_run_simple_parsers_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        3, 
+        Position::new(1, 6, 5)
+    ));
+
+    let actual = sep_by_fold(
+        p_u32,
+        || p_char(';'),
+        0,
+        Box::new(|count, _| count + 1)
+    ).run(String::from("7;8;9"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn sep_by_fold_run_simple_parsers_succeeds_consuming_trailing_separator() {
+    let expected = Ok(ParserSuccess::new(
+        3, 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = sep_by_fold(
+        p_u32,
+        || p_char(';'),
+        0,
+        Box::new(|sum, n| sum + n)
+    ).run(String::from("1;2;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn sep_by_collect_run_simple_parsers_succeeds_collecting_into_hash_set() {
+    let expected = Ok(ParserSuccess::new(
+        HashSet::from([1, 2]), 
+        Position::new(1, 6, 5)
+    ));
+
+    let actual = sep_by_collect::<_, _, HashSet<u32>>(
+        p_u32,
+        || p_char(';')
+    ).run(String::from("1;2;1"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn sep_end_by_run_non_consuming_parsers_fails_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("parser applied by sep_end_by to consume input"), 
+        Some(String::from("parser succeeded without consuming input")),
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = sep_end_by(|| p_u32().opt(), ws)
+        .run(String::from("1 2abc"));
+
+    assert_eq!(actual, expected);
+}
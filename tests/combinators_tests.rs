@@ -80,4 +80,268 @@ fn attempt_run_complex_parsers_fails_with_error() {
         .run(String::from("123def"));
 
     assert_eq!(actual, expected);
-}
\ No newline at end of file
+}
+
+#[test]
+fn lookahead_run_complex_parsers_succeeds_without_consuming_input() {
+    let expected = Ok(ParserSuccess::new(
+        (123, String::from("abc")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let parser = p_u32()
+        .and(p_string(String::from("abc")));
+
+    let actual = lookahead(parser)
+        .run(String::from("123abc"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn lookahead_run_complex_parsers_fails_with_error() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("abc"), 
+        Some(String::from("def")),
+        Position::new(1, 4, 3)
+    ));
+
+    let parser = p_u32()
+        .and(p_string(String::from("abc")));
+
+    let actual = lookahead(parser)
+        .run(String::from("123def"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn lookahead_run_complex_parsers_restores_state_when_nested_parser_uses_marker() {
+    let expected = Ok(ParserSuccess::new(
+        (String::from("abc"), 123), 
+        Position::new(1, 7, 6)
+    ));
+
+    let parser = p_string(String::from("abc"))
+        .and_try(p_u32());
+
+    let actual = lookahead(parser)
+        .take_next(p_string(String::from("abc")).and(p_u32()))
+        .run(String::from("abc123"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn followed_by_run_complex_parsers_succeeds_without_consuming_input() {
+    let expected = Ok(ParserSuccess::new(
+        (), 
+        Position::new(1, 1, 0)
+    ));
+
+    let parser = p_u32()
+        .and(p_string(String::from("abc")));
+
+    let actual = followed_by(parser)
+        .run(String::from("123abc"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn followed_by_run_complex_parsers_fails_with_error_and_composes_with_or() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("123"), 
+        Position::new(1, 4, 3)
+    ));
+
+    let parser = p_u32()
+        .and(p_string(String::from("abc")));
+
+    let actual = followed_by(parser)
+        .then_return(String::from("unreachable"))
+        .or(many_1_digit())
+        .run(String::from("123def"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn not_followed_by_run_simple_parsers_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        (), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = not_followed_by(p_string(String::from("//")))
+        .run(String::from("/ comment"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn not_followed_by_run_simple_parsers_fails_with_error() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("following parser to fail"), 
+        None,
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = not_followed_by(p_string(String::from("//")))
+        .run(String::from("// comment"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn choice_run_with_not_followed_by_tries_next_parser() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("keyword"), 
+        Position::new(1, 3, 2)
+    ));
+
+    let actual = choice(vec![
+        not_followed_by(p_string(String::from("if"))).then_return(String::from("other")),
+        p_string(String::from("if")).then_return(String::from("keyword")),
+    ]).run(String::from("if"));
+
+    assert_eq!(actual, expected);
+}
+
+fn p_let_number() -> Parser<u32> {
+    p_string(String::from("let"))
+        .take_prev(cut())
+        .take_prev(ws())
+        .take_next(p_u32())
+}
+
+#[test]
+fn cut_run_simple_parser_succeeds_without_consuming_input() {
+    let expected = Ok(ParserSuccess::new(
+        (), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = cut()
+        .run(String::from("abc"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn attempt_run_complex_parsers_fails_with_fatal_error_after_cut() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("integral value"), 
+        None, 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = attempt(p_let_number())
+        .run(String::from("let x"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn attempt_run_complex_parsers_fails_with_error_before_cut() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("let"), 
+        Some(String::from("lex")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = attempt(p_let_number())
+        .run(String::from("lex 1"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn choice_run_with_cut_fails_with_fatal_error_instead_of_trying_next_parser() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("integral value"), 
+        None, 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = choice(vec![
+        attempt(p_let_number()),
+        p_string(String::from("let x")).then_return(0),
+    ]).run(String::from("let x"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn choice_run_with_cut_in_successful_attempt_tries_next_parser() {
+    let expected = Ok(ParserSuccess::new(
+        0, 
+        Position::new(1, 8, 7)
+    ));
+
+    let actual = choice(vec![
+        attempt(attempt(p_let_number()).take_prev(p_char(';'))),
+        p_string(String::from("let 1 2")).then_return(0),
+    ]).run(String::from("let 1 2"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn try_take_next_run_with_cut_fails_with_fatal_error_at_second_parser() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("="), 
+        Some(String::from("x")), 
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = p_string(String::from("let"))
+        .take_prev(cut())
+        .try_take_next(p_char('='))
+        .or(p_string(String::from("letx")).then_return('x'))
+        .run(String::from("letx"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn attempt_run_nested_attempt_with_cut_succeeds_at_next_parser() {
+    let expected = Ok(ParserSuccess::new(
+        'a', 
+        Position::new(1, 3, 2)
+    ));
+
+    let actual = attempt(attempt(p_char('a').take_prev(cut()).take_prev(p_char('b'))))
+        .or(p_char('a').take_prev(p_char('x')))
+        .run(String::from("ax"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn attempt_run_try_take_next_with_cut_succeeds_at_next_parser() {
+    let expected = Ok(ParserSuccess::new(
+        'x', 
+        Position::new(1, 3, 2)
+    ));
+
+    let actual = attempt(p_char('a').take_prev(cut()).try_take_next(p_char('b')))
+        .or(p_char('a').take_next(p_char('x')))
+        .run(String::from("ax"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn attempt_run_parser_reverts_across_multi_byte_characters() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("日本語"), 
+        Position::new(1, 10, 9)
+    ));
+
+    let actual = attempt(p_string(String::from("日本")).take_prev(p_char('x')))
+        .or(p_string(String::from("日本語")))
+        .run(String::from("日本語"));
+
+    assert_eq!(actual, expected);
+}
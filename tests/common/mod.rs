@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 use rusty_parsec::*;
 
 pub fn p_true() -> Parser<bool> {
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use rusty_parsec::*;
 
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, PartialEq)]
 enum Json {
     JString(String),
@@ -42,13 +43,13 @@ fn p_json_bool() -> Parser<Json> {
 }
 
 fn p_json_number() -> Parser<Json> {
-    p_f64().map(Box::new(|float| Json::JNumber(float)))
+    p_f64().map(Box::new(Json::JNumber))
         .take_prev(ws())
 }
 
 fn p_json_string() -> Parser<Json> {
     many_satisfy(Box::new(|c: char| c != '\"'))
-        .map(Box::new(|result| Json::JString(result)))
+        .map(Box::new(Json::JString))
         .between(p_char('"'), p_char('"'))
         .take_prev(ws())
 }
@@ -63,7 +64,7 @@ fn p_json_list() -> Parser<Json> {
     
     ws().take_next(p_list)
         .between(p_char('['), p_char(']'))
-        .map(Box::new(|list| Json::JList(list)))
+        .map(Box::new(Json::JList))
         .take_prev(ws())
 }
 
@@ -238,6 +239,21 @@ fn suceeds_parsing_nested_json_objects_and_lists() {
     assert_eq!(expected, actual);
 }
 
+#[test]
+fn succeeds_parsing_json_object_collected_into_hash_map() {
+    let mut expected_map = HashMap::new();
+    expected_map.insert("name".to_string(), Json::JString("Bob".to_string()));
+    expected_map.insert("age".to_string(), Json::JNumber(27.0));
+
+    let expected = Ok(ParserSuccess::new(expected_map, Position::new(1, 26, 25)));
+
+    let actual = ws().take_next(sep_by_collect::<_, _, HashMap<String, Json>>(p_key_value, p_comma))
+        .between(p_char('{'), p_char('}'))
+        .run("{\"name\":\"Bob\", \"age\": 27}".to_string());
+
+    assert_eq!(expected, actual);
+}
+
 fn create_person_with_address(name: String, age: f64, number: f64, street:String) -> Json {
     let mut person = HashMap::new();
     person.insert("name".to_string(), Json::JString(name));
@@ -1,5 +1,12 @@
+use std::cell::Cell;
+use std::time::Duration;
+
 use rusty_parsec::*;
 
+thread_local! {
+    static NUMBER_CALLS: Cell<usize> = const { Cell::new(0) };
+}
+
 #[test]
 fn and_run_simple_parsers_succeeds() {
     let expected = Ok(ParserSuccess::new(
@@ -208,6 +215,64 @@ fn or_run_complex_parsers_fails_with_fatal_error_at_second_parser() {
     assert_eq!(expected, actual);
 }
 
+#[test]
+fn or_either_run_simple_parsers_success_at_first_parser() {
+    let expected = Ok(ParserSuccess::new(
+        Either::Left('a'), 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = p_char('a')
+        .or_either(p_u32())
+        .run(String::from("a12"));
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn or_either_run_simple_parsers_success_at_second_parser() {
+    let expected = Ok(ParserSuccess::new(
+        Either::Right(12), 
+        Position::new(1, 3, 2)
+    ));
+
+    let actual = p_char('a')
+        .or_either(p_u32())
+        .run(String::from("12a"));
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn or_either_run_simple_parsers_fails_with_error_at_second_parser() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("integral value"), 
+        None, 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = p_char('a')
+        .or_either(p_u32())
+        .run(String::from("cba"));
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn or_either_run_complex_parsers_fails_with_fatal_error_at_first_parser() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("b"), 
+        Some(String::from("1")), 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = p_char('a').and(p_char('b'))
+        .or_either(p_u32())
+        .run(String::from("a1"));
+
+    assert_eq!(expected, actual);
+}
+
 #[test]
 fn take_prev_run_simple_parsers_succeeds() {
     let expected = Ok(ParserSuccess::new(
@@ -464,7 +529,7 @@ fn succeeds_parsing_with_bind() {
     let actual =
         p_char('a')
             .then_return("hello".to_string())
-            .bind(Box::new(|hello| p_string(hello)))
+            .bind(Box::new(p_string))
             .run("ahello".to_string());
 
     assert_eq!(expected, actual);
@@ -477,7 +542,7 @@ fn fails_parsing_with_bind() {
     let actual =
         p_char('a')
             .then_return("hello".to_string())
-            .bind(Box::new(|hello| p_string(hello)))
+            .bind(Box::new(p_string))
             .run("aworld".to_string());
 
     assert_eq!(expected, actual);
@@ -490,7 +555,7 @@ fn succeeds_parsing_with_try_bind() {
     let actual =
         p_char('a')
             .then_return("hello".to_string())
-            .try_bind(Box::new(|hello| p_string(hello)))
+            .try_bind(Box::new(p_string))
             .run("ahello".to_string());
 
     assert_eq!(expected, actual);
@@ -503,7 +568,7 @@ fn fails_parsing_with_try_bind() {
     let actual =
         p_char('a')
             .then_return("hello".to_string())
-            .try_bind(Box::new(|hello| p_string(hello)))
+            .try_bind(Box::new(p_string))
             .run("aworld".to_string());
 
     assert_eq!(expected, actual);
@@ -680,4 +745,518 @@ fn fails_parsing_with_not_followed_by() {
             .run("123abchelloworld".to_string());
 
     assert_eq!(expected, actual);
-}
\ No newline at end of file
+}
+
+#[test]
+fn with_span_run_simple_parser_succeeds_across_lines() {
+    let expected = Ok(ParserSuccess::new(
+        (String::from("hello\nworld"), Span::new(Position::new(1, 2, 1), Position::new(2, 6, 12))), 
+        Position::new(2, 6, 12)
+    ));
+
+    let actual = p_char(' ')
+        .take_next(p_string(String::from("hello\nworld")).with_span())
+        .run(String::from(" hello\nworld!"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn with_span_run_simple_parser_fails_with_error() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("integral value"), 
+        None, 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = p_u32().with_span()
+        .run(String::from("abc"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn recognize_run_complex_parser_succeeds_with_consumed_text() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("héllo, wörld"), 
+        Position::new(1, 15, 14)
+    ));
+
+    let actual = many_1_letter()
+        .and(p_string(String::from(", ")))
+        .and(many_1_letter())
+        .recognize()
+        .run(String::from("héllo, wörld!"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn recognize_run_complex_parser_fails_with_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("B"), 
+        Some(String::from("C")), 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = p_char('A')
+        .and(p_char('B'))
+        .recognize()
+        .run(String::from("AC"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn with_recognized_run_simple_parser_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        (-42, String::from("-42")), 
+        Position::new(1, 4, 3)
+    ));
+
+    let actual = p_i32().with_recognized()
+        .run(String::from("-42 "));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn bitor_run_simple_parsers_succeeds_at_second_parser() {
+    let expected = Ok(ParserSuccess::new(
+        'b', 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = (p_char('a') | p_char('b'))
+        .run(String::from("bac"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn bitor_run_complex_parsers_fails_with_fatal_error_at_first_parser() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("b"), 
+        Some(String::from("c")), 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = ((p_char('a') + p_char('b')) | (p_char('a') + p_char('c')))
+        .run(String::from("ac"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn add_run_simple_parsers_fails_with_fatal_error_at_second_parser() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("b"), 
+        Some(String::from("c")), 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = (p_char('a') + p_char('b'))
+        .run(String::from("ac"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn shr_and_shl_run_simple_parsers_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        123, 
+        Position::new(1, 6, 5)
+    ));
+
+    let actual = (p_char('[') >> p_u32() << p_char(']'))
+        .run(String::from("[123]"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn shl_run_simple_parsers_fails_with_fatal_error_at_second_parser() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("]"), 
+        Some(String::from(")")), 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = (p_char('[') >> p_u32() << p_char(']'))
+        .run(String::from("[123)"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn sub_run_simple_parsers_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("if"), 
+        Position::new(1, 3, 2)
+    ));
+
+    let actual = (p_string(String::from("if")) - letter())
+        .run(String::from("if x"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn not_run_simple_parser_fails_with_error_without_consuming_input() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("following parser to fail"), 
+        None, 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = (!p_string(String::from("if")))
+        .run(String::from("if x"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn operators_run_complex_parsers_succeeds() {
+    let expected = Ok(ParserSuccess::new(
+        (String::from("goodbye"), String::from("world")), 
+        Position::new(1, 15, 14)
+    ));
+
+    let parser = 
+        ((p_string(String::from("hello")) | p_string(String::from("goodbye"))) << p_char(',') << ws()) 
+        + p_string(String::from("world"));
+
+    let actual = parser.run(String::from("goodbye, world"));
+
+    assert_eq!(actual, expected);
+}
+
+fn p_counted_number() -> Parser<u32> {
+    p_u32()
+        .map(Box::new(|n| {
+            NUMBER_CALLS.with(|calls| calls.set(calls.get() + 1));
+            n
+        }))
+        .memoize("counted number")
+}
+
+fn p_memoized_pair() -> Parser<(char, char)> {
+    p_char('a').and(p_char('b')).memoize("pair")
+}
+
+#[test]
+fn memoize_run_complex_parsers_succeeds_applying_parser_once() {
+    let expected = Ok(ParserSuccess::new(
+        12, 
+        Position::new(1, 4, 3)
+    ));
+
+    NUMBER_CALLS.with(|calls| calls.set(0));
+
+    let actual = choice(vec![
+        attempt(p_counted_number().take_prev(p_char('+'))),
+        attempt(p_counted_number().take_prev(p_char('-'))),
+        p_counted_number().take_prev(p_char(';')),
+    ]).run(String::from("12;"));
+
+    assert_eq!(actual, expected);
+    assert_eq!(NUMBER_CALLS.with(|calls| calls.get()), 1);
+}
+
+#[test]
+fn memoize_run_complex_parsers_succeeds_tracking_lines_of_cached_result() {
+    let expected = Ok(ParserSuccess::new(
+        String::from("a\nb"), 
+        Position::new(2, 2, 3)
+    ));
+
+    fn p_lines() -> Parser<String> {
+        many_satisfy(Box::new(|c| c != ';')).memoize("lines")
+    }
+
+    let actual = attempt(p_lines().take_prev(p_char(';')).take_prev(p_char(';')))
+        .or(p_lines())
+        .run(String::from("a\nb;"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn memoize_run_complex_parsers_fails_with_cached_fatal_error() {
+    let expected = Err(ParserFailure::new_fatal_err(
+        String::from("b"), 
+        Some(String::from("c")), 
+        Position::new(1, 2, 1)
+    ));
+
+    let actual = attempt(p_memoized_pair())
+        .or(p_memoized_pair())
+        .run(String::from("ac"));
+
+    assert_eq!(actual, expected);
+}
+
+fn p_memoized_keyword(keyword: &str) -> Parser<String> {
+    p_string(keyword.to_string()).memoize(&format!("keyword {}", keyword))
+}
+
+#[test]
+fn memoize_run_complex_parsers_built_at_same_call_site_fails_with_error() {
+    let expected = Err(ParserFailure::new_err(
+        String::from("xy"), 
+        Some(String::from("ab")), 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = attempt(p_memoized_keyword("ab").and(p_char('!')))
+        .or(p_memoized_keyword("xy").and(p_char('z')))
+        .run(String::from("ab"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn trace_run_complex_parsers_records_nested_entries() {
+    let expected = vec![
+        TraceEntry {
+            name: String::from("pair"),
+            start: Position::new(1, 1, 0),
+            outcome: TraceOutcome::Success,
+            end: Position::new(2, 2, 3),
+            children: vec![
+                TraceEntry {
+                    name: String::from("first"),
+                    start: Position::new(1, 1, 0),
+                    outcome: TraceOutcome::Success,
+                    end: Position::new(1, 2, 1),
+                    children: vec![],
+                },
+                TraceEntry {
+                    name: String::from("second"),
+                    start: Position::new(2, 1, 2),
+                    outcome: TraceOutcome::Success,
+                    end: Position::new(2, 2, 3),
+                    children: vec![],
+                },
+            ],
+        },
+    ];
+
+    let (result, trace) = p_char('a').trace("first")
+        .take_prev(p_char('\n'))
+        .and(p_char('b').trace("second"))
+        .trace("pair")
+        .run_traced(String::from("a\nb"));
+
+    assert_eq!(result, Ok(ParserSuccess::new(('a', 'b'), Position::new(2, 2, 3))));
+    assert_eq!(trace.get_entries(), expected.as_slice());
+}
+
+#[test]
+fn trace_run_complex_parsers_records_fatal_error_as_text() {
+    let expected = "\
+Entering keyword: Ln: 1, Col: 1
+  Entering let: Ln: 1, Col: 1
+  Leaving let (Ok): Ln: 1, Col: 4
+Leaving keyword (FatalError): Ln: 1, Col: 4
+Entering name: Ln: 1, Col: 1
+Leaving name (Ok): Ln: 1, Col: 6
+";
+
+    let (_, trace) = p_string(String::from("let")).trace("let")
+        .and(p_char(' '))
+        .trace("keyword")
+        .run_traced(String::from("let_x"));
+
+    let (_, name_trace) = many_satisfy(Box::new(|c: char| c.is_alphanumeric() || c == '_'))
+        .trace("name")
+        .run_traced(String::from("let_x"));
+
+    assert_eq!(format!("{}{}", trace, name_trace), expected);
+}
+
+#[test]
+fn trace_run_with_limits_records_limit_exceeded_as_text() {
+    let expected = "\
+Entering pair: Ln: 1, Col: 1
+  Entering first: Ln: 1, Col: 1
+  Leaving first (Ok): Ln: 1, Col: 2
+  Entering second: Ln: 1, Col: 2
+  Leaving second (LimitExceeded): Ln: 1, Col: 2
+Leaving pair (LimitExceeded): Ln: 1, Col: 2
+";
+
+    let (result, trace) = p_char('a').trace("first")
+        .and(p_char('b').trace("second"))
+        .trace("pair")
+        .run_traced_with_limits(String::from("ab"), ParserLimits::new().with_max_steps(5));
+
+    assert!(result.unwrap_err().is_limit_exceeded());
+    assert_eq!(trace.to_text(), expected);
+}
+
+#[test]
+fn trace_run_simple_parser_writes_escaped_json() {
+    let expected = concat!(
+        r#"[{"name":"quote \"q\"","#,
+        r#""start":{"line":1,"column":1,"index":0},"#,
+        r#""outcome":"Error","#,
+        r#""end":{"line":1,"column":1,"index":0},"#,
+        r#""children":[]}]"#
+    );
+
+    let (_, trace) = p_char('"').trace("quote \"q\"")
+        .run_traced(String::from("q"));
+
+    assert_eq!(trace.to_json(), expected);
+}
+
+#[test]
+fn trace_run_without_debug_mode_returns_parser_result() {
+    let expected = Ok(ParserSuccess::new(
+        12, 
+        Position::new(1, 3, 2)
+    ));
+
+    let actual = p_u32().trace("number")
+        .run(String::from("12"));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn run_profiled_complex_parsers_reports_backtracking_of_named_parsers() {
+    let p_assign = 
+        attempt(p_string(String::from("x")).take_prev(p_string(String::from(" = ")).trace("equals")))
+            .trace("assign");
+    let p_name = p_string(String::from("x ==")).trace("name");
+
+    let (result, profile) = p_assign
+        .or(p_name)
+        .trace("statement")
+        .run_profiled(String::from("x == y"));
+
+    let names: Vec<&str> = profile.get_entries().iter().map(|entry| entry.name.as_str()).collect();
+    let assign = profile.get_entry("assign").unwrap();
+    let equals = profile.get_entry("equals").unwrap();
+    let statement = profile.get_entry("statement").unwrap();
+
+    assert_eq!(result, Ok(ParserSuccess::new(String::from("x =="), Position::new(1, 5, 4))));
+    assert_eq!(names, vec!["assign", "equals", "name", "statement"]);
+    assert_eq!((assign.invocations, assign.successes, assign.failures), (1, 0, 1));
+    assert_eq!((assign.bytes_consumed, assign.bytes_rescanned), (0, 1));
+    assert_eq!((equals.failures, equals.bytes_rescanned), (1, 0));
+    assert_eq!((statement.successes, statement.bytes_consumed, statement.bytes_rescanned), (1, 4, 1));
+}
+
+#[test]
+fn run_profiled_simple_parser_reports_nothing_without_named_parsers() {
+    let expected = Ok(ParserSuccess::new(
+        12, 
+        Position::new(1, 3, 2)
+    ));
+
+    let (actual, profile) = p_u32()
+        .run_profiled(String::from("12"));
+
+    assert_eq!(actual, expected);
+    assert!(profile.get_entries().is_empty());
+    assert!(profile.to_string().starts_with("name"));
+}
+
+#[test]
+fn run_with_limits_complex_parser_succeeds_within_limits() {
+    let expected = Ok(ParserSuccess::new(
+        vec![1, 2, 3], 
+        Position::new(1, 6, 5)
+    ));
+
+    let limits = ParserLimits::new()
+        .with_max_steps(100)
+        .with_max_depth(10)
+        .with_max_backtrack(0)
+        .with_timeout(Duration::from_secs(10));
+
+    let actual = sep_by(p_u32, || p_char(','))
+        .run_with_limits(String::from("1,2,3"), limits);
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn run_with_limits_complex_parser_fails_with_limit_exceeded_at_max_steps() {
+    let expected = Err(ParserFailure::new_limit_err(
+        String::from("at most 10 parser steps"), 
+        Some(String::from("11 parser steps")), 
+        Position::new(1, 10, 9)
+    ));
+
+    let actual = many(|| p_char('a'))
+        .run_with_limits("a".repeat(100), ParserLimits::new().with_max_steps(10));
+
+    assert_eq!(actual, expected);
+}
+
+fn p_nested() -> Parser<u32> {
+    p_char('(')
+        .take_next(rule(p_nested))
+        .take_prev(p_char(')'))
+        .or(p_u32())
+}
+
+#[test]
+fn run_with_limits_recursive_parser_fails_with_limit_exceeded_at_max_depth() {
+    let input = format!("{}1{}", "(".repeat(10_000), ")".repeat(10_000));
+
+    let actual = rule(p_nested)
+        .run_with_limits(input, ParserLimits::new().with_max_depth(50));
+
+    let failure = actual.unwrap_err();
+
+    assert!(failure.is_limit_exceeded());
+    assert!(failure.to_err_msg().contains("parsers nested at most 50 deep"));
+}
+
+#[test]
+fn run_with_limits_complex_parser_fails_with_limit_exceeded_at_max_backtrack() {
+    let expected = Err(ParserFailure::new_limit_err(
+        String::from("backtracking of at most 2 bytes"), 
+        Some(String::from("backtracking of 4 bytes")), 
+        Position::new(1, 5, 4)
+    ));
+
+    let actual = attempt(p_string(String::from("abcd")).and(p_char('e')))
+        .or(p_string(String::from("abcd")).and(p_char('f')))
+        .run_with_limits(String::from("abcdf"), ParserLimits::new().with_max_backtrack(2));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn run_with_limits_complex_parser_fails_with_limit_exceeded_at_timeout() {
+    let expected = Err(ParserFailure::new_limit_err(
+        String::from("parsing to finish within 0ns"), 
+        None, 
+        Position::new(1, 1, 0)
+    ));
+
+    let actual = many(|| p_char('a'))
+        .run_with_limits(String::from("aaa"), ParserLimits::new().with_timeout(Duration::ZERO));
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn run_with_limits_limit_exceeded_is_not_recovered_by_attempt_or_optional() {
+    let p_letters = || many(|| p_char('a'));
+
+    let actual = attempt(p_letters())
+        .or(p_letters())
+        .and(p_char('b').opt())
+        .run_with_limits(String::from("aaaaaab"), ParserLimits::new().with_max_steps(4));
+
+    let failure = actual.unwrap_err();
+
+    assert!(failure.is_limit_exceeded());
+    assert!(failure.is_fatal());
+}